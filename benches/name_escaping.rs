@@ -4,12 +4,12 @@ use kotlin_poet_rs::spec::Name;
 
 fn name_escaping_escaped() -> String {
     let name = Name::from("Hello, World!");
-    return name.render_string();
+    name.render_string()
 }
 
 fn name_escaping_unescaped() -> String {
     let name = Name::from("HelloWorld");
-    return name.render_string();
+    name.render_string()
 }
 
 fn criterion_benchmark(c: &mut Criterion) {
@@ -0,0 +1,40 @@
+use crate::spec::fn_annotation_factory;
+use crate::spec::Annotation;
+
+/// [androidx.annotation](https://developer.android.com/reference/androidx/annotation/package-summary) annotations
+impl Annotation {
+    // Nullability
+    fn_annotation_factory!(androidx_nullable, androidx.annotation, Nullable);
+    fn_annotation_factory!(androidx_non_null, androidx.annotation, NonNull);
+
+    // Threading
+    fn_annotation_factory!(androidx_main_thread, androidx.annotation, MainThread);
+    fn_annotation_factory!(androidx_ui_thread, androidx.annotation, UiThread);
+    fn_annotation_factory!(androidx_worker_thread, androidx.annotation, WorkerThread);
+    fn_annotation_factory!(androidx_any_thread, androidx.annotation, AnyThread);
+
+    // Resources
+    fn_annotation_factory!(androidx_string_res, androidx.annotation, StringRes);
+    fn_annotation_factory!(androidx_drawable_res, androidx.annotation, DrawableRes);
+    fn_annotation_factory!(androidx_color_res, androidx.annotation, ColorRes);
+    fn_annotation_factory!(androidx_dimen_res, androidx.annotation, DimenRes);
+    fn_annotation_factory!(androidx_color_int, androidx.annotation, ColorInt);
+
+    // Code shrinking and visibility
+    fn_annotation_factory!(androidx_keep, androidx.annotation, Keep);
+    fn_annotation_factory!(androidx_visible_for_testing, androidx.annotation, VisibleForTesting);
+    fn_annotation_factory!(androidx_call_super, androidx.annotation, CallSuper);
+    fn_annotation_factory!(androidx_check_result, androidx.annotation, CheckResult);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::RenderKotlin;
+    use crate::spec::Annotation;
+
+    #[test]
+    fn render_androidx_annotations() {
+        assert_eq!(Annotation::androidx_main_thread().render_string(), "@androidx.annotation.MainThread()");
+        assert_eq!(Annotation::androidx_nullable().render_string(), "@androidx.annotation.Nullable()");
+    }
+}
@@ -0,0 +1,37 @@
+use crate::spec::fn_basic_type_factory;
+use crate::spec::Type;
+
+/// Commonly used JDK types
+impl Type {
+    // java.time
+    fn_basic_type_factory!(java_instant, java.time, Instant);
+    fn_basic_type_factory!(java_local_date, java.time, LocalDate);
+    fn_basic_type_factory!(java_local_time, java.time, LocalTime);
+    fn_basic_type_factory!(java_local_date_time, java.time, LocalDateTime);
+    fn_basic_type_factory!(java_offset_date_time, java.time, OffsetDateTime);
+    fn_basic_type_factory!(java_zoned_date_time, java.time, ZonedDateTime);
+    fn_basic_type_factory!(java_zone_id, java.time, ZoneId);
+    fn_basic_type_factory!(java_duration, java.time, Duration);
+    fn_basic_type_factory!(java_period, java.time, Period);
+
+    // java.util
+    fn_basic_type_factory!(java_uuid, java.util, UUID);
+
+    // java.math
+    fn_basic_type_factory!(java_big_decimal, java.math, BigDecimal);
+    fn_basic_type_factory!(java_big_integer, java.math, BigInteger);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::RenderKotlin;
+    use crate::spec::Type;
+
+    #[test]
+    fn render_java_types() {
+        assert_eq!(Type::java_local_date_time().render_string(), "java.time.LocalDateTime");
+        assert_eq!(Type::java_duration().render_string(), "java.time.Duration");
+        assert_eq!(Type::java_uuid().render_string(), "java.util.UUID");
+        assert_eq!(Type::java_big_decimal().render_string(), "java.math.BigDecimal");
+    }
+}
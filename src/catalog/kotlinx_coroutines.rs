@@ -0,0 +1,38 @@
+use crate::spec::{fn_basic_type_factory, fn_generic_type_factory};
+use crate::spec::Type;
+
+/// [kotlinx.coroutines](https://github.com/Kotlin/kotlinx.coroutines) types
+impl Type {
+    // Scopes and jobs
+    fn_basic_type_factory!(coroutines_scope, kotlinx.coroutines, CoroutineScope);
+    fn_basic_type_factory!(coroutines_dispatcher, kotlinx.coroutines, CoroutineDispatcher);
+    fn_basic_type_factory!(coroutines_job, kotlinx.coroutines, Job);
+    fn_generic_type_factory!(coroutines_deferred, kotlinx.coroutines, Deferred<value>);
+
+    // Flows
+    fn_generic_type_factory!(coroutines_flow, kotlinx.coroutines.flow, Flow<value>);
+    fn_generic_type_factory!(coroutines_shared_flow, kotlinx.coroutines.flow, SharedFlow<value>);
+    fn_generic_type_factory!(coroutines_mutable_shared_flow, kotlinx.coroutines.flow, MutableSharedFlow<value>);
+    fn_generic_type_factory!(coroutines_state_flow, kotlinx.coroutines.flow, StateFlow<value>);
+    fn_generic_type_factory!(coroutines_mutable_state_flow, kotlinx.coroutines.flow, MutableStateFlow<value>);
+
+    // Channels
+    fn_generic_type_factory!(coroutines_channel, kotlinx.coroutines.channels, Channel<value>);
+    fn_generic_type_factory!(coroutines_send_channel, kotlinx.coroutines.channels, SendChannel<value>);
+    fn_generic_type_factory!(coroutines_receive_channel, kotlinx.coroutines.channels, ReceiveChannel<value>);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::RenderKotlin;
+    use crate::spec::Type;
+
+    #[test]
+    fn render_coroutines_types() {
+        assert_eq!(Type::coroutines_scope().render_string(), "kotlinx.coroutines.CoroutineScope");
+        assert_eq!(
+            Type::coroutines_state_flow(Type::string()).render_string(),
+            "kotlinx.coroutines.flow.StateFlow<kotlin.String>"
+        );
+    }
+}
@@ -0,0 +1,52 @@
+use crate::spec::fn_annotation_factory;
+use crate::spec::{fn_basic_type_factory, fn_generic_type_factory};
+use crate::spec::{Annotation, Argument, ClassLikeTypeName, CodeBlock, Type};
+
+/// [kotlinx.serialization](https://github.com/Kotlin/kotlinx.serialization) types
+impl Type {
+    fn_generic_type_factory!(serialization_k_serializer, kotlinx.serialization, KSerializer<value>);
+    fn_basic_type_factory!(serialization_json, kotlinx.serialization.json, Json);
+    fn_basic_type_factory!(serialization_json_element, kotlinx.serialization.json, JsonElement);
+    fn_basic_type_factory!(serialization_json_object, kotlinx.serialization.json, JsonObject);
+    fn_basic_type_factory!(serialization_json_array, kotlinx.serialization.json, JsonArray);
+    fn_basic_type_factory!(serialization_json_primitive, kotlinx.serialization.json, JsonPrimitive);
+}
+
+/// [kotlinx.serialization](https://github.com/Kotlin/kotlinx.serialization) annotations
+impl Annotation {
+    fn_annotation_factory!(serialization_serializable, kotlinx.serialization, Serializable);
+    fn_annotation_factory!(serialization_transient, kotlinx.serialization, Transient);
+    fn_annotation_factory!(serialization_contextual, kotlinx.serialization, Contextual);
+    fn_annotation_factory!(serialization_required, kotlinx.serialization, Required);
+
+    /// Creates `@kotlinx.serialization.SerialName(value)` annotation.
+    /// [value] is raw Kotlin code, so string literal should be passed with quotes, e.g. `"\"user_id\""`.
+    pub fn serialization_serial_name<CodeBlockLike: Into<CodeBlock>>(value: CodeBlockLike) -> Annotation {
+        Annotation::new(ClassLikeTypeName::from("kotlinx.serialization.SerialName"))
+            .argument(Argument::new_positional(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::RenderKotlin;
+    use crate::spec::{Annotation, Type};
+
+    #[test]
+    fn render_serialization_types() {
+        assert_eq!(
+            Type::serialization_k_serializer(Type::string()).render_string(),
+            "kotlinx.serialization.KSerializer<kotlin.String>"
+        );
+        assert_eq!(Type::serialization_json_element().render_string(), "kotlinx.serialization.json.JsonElement");
+    }
+
+    #[test]
+    fn render_serialization_annotations() {
+        assert_eq!(Annotation::serialization_serializable().render_string(), "@kotlinx.serialization.Serializable()");
+        assert_eq!(
+            Annotation::serialization_serial_name("\"user_id\"").render_string(),
+            "@kotlinx.serialization.SerialName(\"user_id\")"
+        );
+    }
+}
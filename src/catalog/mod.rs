@@ -0,0 +1,23 @@
+//! Optional catalogs of well known third-party Kotlin types and annotations.
+//!
+//! Each catalog is gated by its own cargo feature and extends [crate::spec::Type] and [crate::spec::Annotation]
+//! with factory functions, in the same way stdlib types are available via [crate::spec::Type::list].
+//! Factories are prefixed with the catalog name, e.g. `Type::coroutines_flow`:
+//!
+//! | Feature                         | Library                                    | Prefix           |
+//! |---------------------------------|--------------------------------------------|------------------|
+//! | `catalog-kotlinx-coroutines`    | `kotlinx.coroutines`                       | `coroutines_`    |
+//! | `catalog-kotlinx-serialization` | `kotlinx.serialization`                    | `serialization_` |
+//! | `catalog-java`                  | `java.time`, `java.util.UUID`, `java.math` | `java_`          |
+//! | `catalog-androidx`              | `androidx.annotation`                      | `androidx_`      |
+//!
+//! `catalog-all` enables all of them.
+
+#[cfg(feature = "catalog-kotlinx-coroutines")]
+mod kotlinx_coroutines;
+#[cfg(feature = "catalog-kotlinx-serialization")]
+mod kotlinx_serialization;
+#[cfg(feature = "catalog-java")]
+mod java;
+#[cfg(feature = "catalog-androidx")]
+mod androidx;
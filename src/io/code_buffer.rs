@@ -14,7 +14,7 @@ impl CodeBuffer {
     }
 
     pub(crate) fn last_char(&self) -> Option<char> {
-        return self.buffer.chars().last();
+        self.buffer.chars().last()
     }
 
     const SPACE_LIKE: [char; 2] = [' ', '\n'];
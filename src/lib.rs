@@ -5,3 +5,6 @@ pub mod io;
 pub mod spec;
 mod util;
 pub mod tokens;
+pub mod catalog;
+
+pub use util::{SemanticConversionError, ValidationError};
@@ -0,0 +1,38 @@
+use crate::spec::{Annotation, Type};
+
+/// Type with type-use annotations rendered before it (e.g. `@Composable () -> kotlin.Unit`).
+/// Can only be created via [Type::annotation], so annotations are never empty and never nested.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone)]
+pub struct AnnotatedType {
+    annotations: Vec<Annotation>,
+    inner: Box<Type>,
+}
+
+impl AnnotatedType {
+    pub(crate) fn new(annotation: Annotation, inner: Type) -> AnnotatedType {
+        AnnotatedType {
+            annotations: vec![annotation],
+            inner: Box::new(inner),
+        }
+    }
+
+    /// Type-use annotations in order of declaration
+    pub fn annotations(&self) -> &[Annotation] {
+        &self.annotations
+    }
+
+    /// Annotated type
+    pub fn inner(&self) -> &Type {
+        &self.inner
+    }
+
+    pub(crate) fn push(&mut self, annotation: Annotation) {
+        self.annotations.push(annotation);
+    }
+
+    /// Replaces inner type with the result of [transform], keeping annotations
+    pub(crate) fn map_inner<F: FnOnce(Type) -> Type>(mut self, transform: F) -> AnnotatedType {
+        *self.inner = transform(*self.inner);
+        self
+    }
+}
@@ -1,7 +1,38 @@
 use crate::io::RenderKotlin;
-use crate::spec::{AnnotationTarget, Argument, ClassLikeTypeName, CodeBlock};
+use crate::spec::{AnnotationClassTarget, AnnotationRetention, AnnotationTarget, Argument, ClassLikeTypeName, CodeBlock};
 use crate::tokens;
 
+// region annotations codegen
+macro_rules! fn_annotation_factory {
+    ($identifier:ident, $($package:tt).+, $class:ident) => {
+        #[doc = concat!(
+            "Creates `@",
+            stringify!($($package).+),
+            ".",
+            stringify!($class),
+            "` annotation without arguments"
+        )]
+        pub fn $identifier() -> $crate::spec::Annotation {
+            use std::str::FromStr;
+
+            let package = $crate::spec::Package::from_str(stringify!($($package).+)).unwrap();
+            let name = $crate::spec::Name::from_str(stringify!($class)).unwrap();
+
+            $crate::spec::Annotation::new(
+                $crate::spec::ClassLikeTypeName::top_level(
+                    package,
+                    name
+                )
+            )
+        }
+    };
+}
+
+// re-exported for catalogs only
+#[cfg(any(feature = "catalog-kotlinx-serialization", feature = "catalog-androidx"))]
+pub(crate) use fn_annotation_factory;
+// endregion annotations codegen
+
 /// Represents an annotation in Kotlin. Used for adding meta information for code entities.
 ///
 /// [Official documentation reference](https://kotlinlang.org/docs/annotations.html)
@@ -29,7 +60,7 @@ use crate::tokens;
 ///     "@a.b.c.MyAnnotation(value = 1, name = \"name_value\")"
 /// );
 /// ```
-#[derive(Debug, Clone)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone)]
 pub struct Annotation {
     type_name: ClassLikeTypeName,
     arguments: Vec<Argument>,
@@ -64,6 +95,11 @@ impl AnnotationSlot {
     pub(crate) fn push(&mut self, new: Annotation) {
         self.inner.push(new)
     }
+
+    /// Checks if annotation of [type_name] class is already added
+    pub(crate) fn contains(&self, type_name: &ClassLikeTypeName) -> bool {
+        self.inner.iter().any(|annotation| &annotation.type_name == type_name)
+    }
 }
 
 impl RenderKotlin for AnnotationSlot {
@@ -100,19 +136,59 @@ impl Annotation {
         self.target = Some(target);
         self
     }
-}
 
-impl RenderKotlin for Annotation {
-    fn render_into(&self, block: &mut CodeBlock) {
+    /// Name of the annotation class
+    pub fn type_name(&self) -> &ClassLikeTypeName {
+        &self.type_name
+    }
+
+    /// Creates `@kotlin.annotation.Target(...)` meta-annotation, restricting where annotation class can be applied
+    pub fn annotation_target<Targets: IntoIterator<Item = AnnotationClassTarget>>(targets: Targets) -> Annotation {
+        targets.into_iter().fold(
+            Annotation::new(ClassLikeTypeName::from("kotlin.annotation.Target")),
+            |annotation, target| annotation.argument(Annotation::meta_argument(&target)),
+        )
+    }
+
+    /// Creates `@kotlin.annotation.Retention(...)` meta-annotation
+    pub fn retention(retention: AnnotationRetention) -> Annotation {
+        Annotation::new(ClassLikeTypeName::from("kotlin.annotation.Retention"))
+            .argument(Annotation::meta_argument(&retention))
+    }
+
+    fn_annotation_factory!(repeatable, kotlin.annotation, Repeatable);
+    fn_annotation_factory!(must_be_documented, kotlin.annotation, MustBeDocumented);
+    fn_annotation_factory!(jvm_inline, kotlin.jvm, JvmInline);
+
+    /// Renders annotation omitting empty argument list, e.g. `@Composable` instead of `@Composable()`
+    pub(crate) fn render_compact_into(&self, block: &mut CodeBlock) {
+        self.render_with(block, true);
+    }
+
+    fn render_with(&self, block: &mut CodeBlock, omit_empty_arguments: bool) {
         block.push_static_atom(tokens::AT);
         if let Some(target) = &self.target {
             block.push_renderable(target);
             block.push_static_atom(tokens::COLON);
         }
         block.push_renderable(&self.type_name);
-        block.push_round_brackets(|inner_code| {
-            inner_code.push_comma_separated(&self.arguments)
-        });
+        if !(omit_empty_arguments && self.arguments.is_empty()) {
+            block.push_round_brackets(|inner_code| {
+                inner_code.push_comma_separated(&self.arguments)
+            });
+        }
+    }
+
+    fn meta_argument<Renderable: RenderKotlin>(value: &Renderable) -> Argument {
+        let mut code = CodeBlock::empty();
+        code.push_renderable(value);
+        Argument::new_positional(code)
+    }
+}
+
+impl RenderKotlin for Annotation {
+    fn render_into(&self, block: &mut CodeBlock) {
+        self.render_with(block, false);
     }
 }
 
@@ -133,7 +209,21 @@ pub(crate) use mixin_annotation_mutators;
 mod tests {
     use std::str::FromStr;
     use crate::io::RenderKotlin;
-    use crate::spec::{Annotation, AnnotationTarget, Argument, ClassLikeTypeName, CodeBlock, Package};
+    use crate::spec::{Annotation, AnnotationClassTarget, AnnotationRetention, AnnotationTarget, Argument, ClassLikeTypeName, CodeBlock, Package};
+
+    #[test]
+    fn test_meta_annotations() {
+        assert_eq!(
+            Annotation::annotation_target([AnnotationClassTarget::Class, AnnotationClassTarget::Function]).render_string(),
+            "@kotlin.annotation.Target(kotlin.annotation.AnnotationTarget.CLASS, kotlin.annotation.AnnotationTarget.FUNCTION)"
+        );
+        assert_eq!(
+            Annotation::retention(AnnotationRetention::Binary).render_string(),
+            "@kotlin.annotation.Retention(kotlin.annotation.AnnotationRetention.BINARY)"
+        );
+        assert_eq!(Annotation::repeatable().render_string(), "@kotlin.annotation.Repeatable()");
+        assert_eq!(Annotation::must_be_documented().render_string(), "@kotlin.annotation.MustBeDocumented()");
+    }
 
     #[test]
     fn test_annotation() {
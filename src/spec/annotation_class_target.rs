@@ -0,0 +1,75 @@
+use crate::io::RenderKotlin;
+use crate::spec::CodeBlock;
+
+/// Kind of code element annotation class can be applied to, see [crate::spec::Annotation::annotation_target].
+/// Not to be confused with use-site [crate::spec::AnnotationTarget].
+///
+/// [Kotlin documentation reference](https://kotlinlang.org/api/core/kotlin-stdlib/kotlin.annotation/-annotation-target/)
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub enum AnnotationClassTarget {
+    /// Class, interface or object, annotation class is also included
+    Class,
+    /// Annotation class only
+    AnnotationClass,
+    /// Generic type parameter
+    TypeParameter,
+    /// Property
+    Property,
+    /// Field, including property's backing field
+    Field,
+    /// Local variable
+    LocalVariable,
+    /// Value parameter of a function or a constructor
+    ValueParameter,
+    /// Constructor only (primary or secondary)
+    Constructor,
+    /// Function (constructors are not included)
+    Function,
+    /// Property getter only
+    PropertyGetter,
+    /// Property setter only
+    PropertySetter,
+    /// Type usage
+    Type,
+    /// Any expression
+    Expression,
+    /// File
+    File,
+    /// Type alias
+    TypeAlias,
+}
+
+impl RenderKotlin for AnnotationClassTarget {
+    fn render_into(&self, block: &mut CodeBlock) {
+        let atom = match self {
+            AnnotationClassTarget::Class => "kotlin.annotation.AnnotationTarget.CLASS",
+            AnnotationClassTarget::AnnotationClass => "kotlin.annotation.AnnotationTarget.ANNOTATION_CLASS",
+            AnnotationClassTarget::TypeParameter => "kotlin.annotation.AnnotationTarget.TYPE_PARAMETER",
+            AnnotationClassTarget::Property => "kotlin.annotation.AnnotationTarget.PROPERTY",
+            AnnotationClassTarget::Field => "kotlin.annotation.AnnotationTarget.FIELD",
+            AnnotationClassTarget::LocalVariable => "kotlin.annotation.AnnotationTarget.LOCAL_VARIABLE",
+            AnnotationClassTarget::ValueParameter => "kotlin.annotation.AnnotationTarget.VALUE_PARAMETER",
+            AnnotationClassTarget::Constructor => "kotlin.annotation.AnnotationTarget.CONSTRUCTOR",
+            AnnotationClassTarget::Function => "kotlin.annotation.AnnotationTarget.FUNCTION",
+            AnnotationClassTarget::PropertyGetter => "kotlin.annotation.AnnotationTarget.PROPERTY_GETTER",
+            AnnotationClassTarget::PropertySetter => "kotlin.annotation.AnnotationTarget.PROPERTY_SETTER",
+            AnnotationClassTarget::Type => "kotlin.annotation.AnnotationTarget.TYPE",
+            AnnotationClassTarget::Expression => "kotlin.annotation.AnnotationTarget.EXPRESSION",
+            AnnotationClassTarget::File => "kotlin.annotation.AnnotationTarget.FILE",
+            AnnotationClassTarget::TypeAlias => "kotlin.annotation.AnnotationTarget.TYPEALIAS",
+        };
+        block.push_atom(atom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::RenderKotlin;
+    use crate::spec::AnnotationClassTarget;
+
+    #[test]
+    fn test_render() {
+        assert_eq!(AnnotationClassTarget::Class.render_string(), "kotlin.annotation.AnnotationTarget.CLASS");
+        assert_eq!(AnnotationClassTarget::TypeAlias.render_string(), "kotlin.annotation.AnnotationTarget.TYPEALIAS");
+    }
+}
@@ -0,0 +1,39 @@
+use crate::io::RenderKotlin;
+use crate::spec::CodeBlock;
+
+/// Defines whether annotation is stored in binary output and visible for reflection, see [crate::spec::Annotation::retention].
+///
+/// [Kotlin documentation reference](https://kotlinlang.org/api/core/kotlin-stdlib/kotlin.annotation/-annotation-retention/)
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Default)]
+pub enum AnnotationRetention {
+    /// Annotation isn't stored in binary output
+    Source,
+    /// Annotation is stored in binary output, but invisible for reflection
+    Binary,
+    /// Annotation is stored in binary output and visible for reflection, Kotlin's default
+    #[default]
+    Runtime,
+}
+
+impl RenderKotlin for AnnotationRetention {
+    fn render_into(&self, block: &mut CodeBlock) {
+        let atom = match self {
+            AnnotationRetention::Source => "kotlin.annotation.AnnotationRetention.SOURCE",
+            AnnotationRetention::Binary => "kotlin.annotation.AnnotationRetention.BINARY",
+            AnnotationRetention::Runtime => "kotlin.annotation.AnnotationRetention.RUNTIME",
+        };
+        block.push_atom(atom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::RenderKotlin;
+    use crate::spec::AnnotationRetention;
+
+    #[test]
+    fn test_render() {
+        assert_eq!(AnnotationRetention::Source.render_string(), "kotlin.annotation.AnnotationRetention.SOURCE");
+        assert_eq!(AnnotationRetention::Runtime.render_string(), "kotlin.annotation.AnnotationRetention.RUNTIME");
+    }
+}
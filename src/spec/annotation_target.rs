@@ -6,7 +6,7 @@ use crate::tokens;
 /// Missing targets are represented as [Option<AnnotationTarget>]
 ///
 /// [Kotlin documentation reference](https://kotlinlang.org/docs/annotations.html#annotation-use-site-targets)
-#[derive(Debug, Clone)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone)]
 pub enum AnnotationTarget {
     /// Annotation applied to file
     File,
@@ -32,7 +32,7 @@ use crate::tokens;
 ///
 /// assert_eq!(argument.render_string(), "value");
 /// ```
-#[derive(Debug, Clone)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone)]
 pub struct Argument {
     name: Option<Name>,
     value: CodeBlock,
@@ -1,8 +1,11 @@
 use crate::io::RenderKotlin;
-use crate::spec::{VisibilityModifier, Argument, ClassInheritanceModifier, CodeBlock, CompanionObject, Function, GenericParameter, Name, PrimaryConstructor, Property, SecondaryConstructor, Type, Annotation};
+use std::collections::BTreeSet;
+
+use crate::spec::{VisibilityModifier, Argument, ClassInheritanceModifier, ClassKind, ClassMember, MemberOrder, ClassModifier, EnumEntry, Supertype, ClassLikeType, ClassLikeTypeName, Package, TypeProjection, CodeBlock, CompanionObject, Function, GenericParameter, Name, PrimaryConstructor, Property, SecondaryConstructor, Type, TypeAlias, Annotation};
 use crate::spec::annotation::{mixin_annotation_mutators, AnnotationSlot};
 use crate::spec::kdoc::{KdocSlot, mixin_kdoc_mutators};
 use crate::tokens;
+use crate::util::ValidationError;
 
 #[derive(Debug, Clone)]
 pub(crate) enum ClassMemberNode {
@@ -11,41 +14,71 @@ pub(crate) enum ClassMemberNode {
     Subclass(Class),
     SecondaryConstructor(SecondaryConstructor),
     InitBlock(CodeBlock),
+    TypeAlias(TypeAlias),
 }
 
-impl RenderKotlin for ClassMemberNode {
-    fn render_into(&self, block: &mut CodeBlock) {
+impl ClassMemberNode {
+    pub(crate) fn as_member(&self) -> ClassMember<'_> {
         match self {
-            ClassMemberNode::Property(property) => {
-                block.push_renderable(property);
-            }
-            ClassMemberNode::Function(function) => {
-                block.push_renderable(function);
-            }
-            ClassMemberNode::Subclass(subclass) => {
-                block.push_renderable(subclass);
-            }
-            ClassMemberNode::SecondaryConstructor(secondary_constructor) => {
-                block.push_renderable(secondary_constructor);
+            ClassMemberNode::Property(property) => ClassMember::Property(property),
+            ClassMemberNode::Function(function) => ClassMember::Function(function),
+            ClassMemberNode::Subclass(subclass) => ClassMember::Subclass(subclass),
+            ClassMemberNode::SecondaryConstructor(secondary_constructor) => ClassMember::SecondaryConstructor(secondary_constructor),
+            ClassMemberNode::InitBlock(code) => ClassMember::InitBlock(code),
+            ClassMemberNode::TypeAlias(type_alias) => ClassMember::TypeAlias(type_alias),
+        }
+    }
+
+    /// Validates [members] of class-like body named [owner], abstract functions are rejected unless [allows_abstract_members]
+    pub(crate) fn validate_all(members: &[ClassMemberNode], allows_abstract_members: bool, owner: &str) -> Result<(), ValidationError> {
+        for node in members {
+            match node {
+                ClassMemberNode::Subclass(subclass) => subclass.validate()?,
+                ClassMemberNode::Property(property) => property.validate()?,
+                ClassMemberNode::Function(function) => {
+                    function.validate()?;
+                    if function.is_abstract() && !allows_abstract_members {
+                        return Err(ValidationError::new(&format!(
+                            "Abstract function `{}` is not allowed in non-abstract `{}`",
+                            function.name().render_string(),
+                            owner
+                        )));
+                    }
+                }
+                _ => {}
             }
-            ClassMemberNode::InitBlock(code) => {
+        }
+
+        Ok(())
+    }
+}
+
+impl RenderKotlin for ClassMember<'_> {
+    fn render_into(&self, block: &mut CodeBlock) {
+        match self {
+            ClassMember::Property(property) => block.push_renderable(*property),
+            ClassMember::Function(function) => block.push_renderable(*function),
+            ClassMember::Subclass(subclass) => block.push_renderable(*subclass),
+            ClassMember::SecondaryConstructor(secondary_constructor) => block.push_renderable(*secondary_constructor),
+            ClassMember::TypeAlias(type_alias) => block.push_renderable(*type_alias),
+            ClassMember::InitBlock(code) => {
                 block.push_static_atom(tokens::keyword::INIT);
                 block.push_curly_brackets(|block| {
-                    block.push_renderable(code);
+                    block.push_renderable(*code);
                 });
             }
         }
     }
 }
 
-#[derive(Debug, Clone)]
-struct EnumInstance {
-    name: Name,
-    arguments: Vec<Argument>,
+impl RenderKotlin for ClassMemberNode {
+    fn render_into(&self, block: &mut CodeBlock) {
+        block.push_renderable(&self.as_member());
+    }
 }
 
 /// Defines Kotlin's class like entity. This could represent any 'flavour' of class: enum, interface, e.t.c.
-/// To change type of class please use [Class::inheritance_modifier].
+/// To change type of class please use [Class::kind] and [Class::modifier], or [Class::inheritance_modifier] shorthand.
 ///
 /// #Example
 ///
@@ -69,18 +102,31 @@ struct EnumInstance {
 ///
 ///  assert_eq!(class.render_string(), "public interface Person {\n\n}");
 /// ```
+///
+/// ## Sealed interface
+/// ```
+/// use kotlin_poet_rs::io::RenderKotlin;
+/// use kotlin_poet_rs::spec::{Class, ClassKind, ClassModifier, Name};
+///
+/// let class = Class::new(Name::from("Event"))
+///     .kind(ClassKind::Interface)
+///     .modifier(ClassModifier::Sealed);
+///
+///  assert_eq!(class.render_string(), "public sealed interface Event {\n\n}");
+/// ```
 #[derive(Debug, Clone)]
 pub struct Class {
     name: Name,
     visibility_modifier: VisibilityModifier,
-    inheritance_modifier: ClassInheritanceModifier,
+    kind: ClassKind,
+    modifiers: BTreeSet<ClassModifier>,
     member_nodes: Vec<ClassMemberNode>,
-    enum_instances: Vec<EnumInstance>,
+    member_order: MemberOrder,
+    enum_entries: Vec<EnumEntry>,
     primary_constructor: Option<PrimaryConstructor>,
     companion_object: Option<CompanionObject>,
     generic_parameters: Vec<GenericParameter>,
-    parent_classes: Vec<Type>,
-    is_inner: bool,
+    parent_classes: Vec<Supertype>,
     annotation_slot: AnnotationSlot,
     kdoc: KdocSlot,
 }
@@ -91,22 +137,46 @@ impl Class {
         Class {
             name: name.into(),
             visibility_modifier: VisibilityModifier::default(),
-            inheritance_modifier: ClassInheritanceModifier::default(),
+            kind: ClassKind::default(),
+            modifiers: BTreeSet::default(),
             member_nodes: Vec::default(),
-            enum_instances: Vec::default(),
+            member_order: MemberOrder::default(),
+            enum_entries: Vec::default(),
             primary_constructor: None,
             companion_object: None,
             generic_parameters: Vec::default(),
             parent_classes: Vec::default(),
-            is_inner: false,
             annotation_slot: AnnotationSlot::vertical(),
             kdoc: KdocSlot::default(),
         }
     }
 
-    /// Marks class as inner
+    /// Marks class as inner, same as adding [ClassModifier::Inner]
     pub fn inner(mut self, flag: bool) -> Self {
-        self.is_inner = flag;
+        if flag {
+            self.modifiers.insert(ClassModifier::Inner);
+        } else {
+            self.modifiers.remove(&ClassModifier::Inner);
+        }
+        self
+    }
+
+    /// Sets [ClassKind], modifiers are kept
+    pub fn kind(mut self, kind: ClassKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Adds [ClassModifier], modifiers are always rendered in canonical order.
+    /// Modality modifier (`open`, `abstract`, `sealed`) replaces previously added one, same applies to `expect` / `actual`.
+    pub fn modifier(mut self, modifier: ClassModifier) -> Self {
+        if modifier.is_modality() {
+            self.modifiers.retain(|it| !it.is_modality());
+        }
+        if modifier.is_multiplatform() {
+            self.modifiers.retain(|it| !it.is_multiplatform());
+        }
+        self.modifiers.insert(modifier);
         self
     }
 
@@ -116,9 +186,15 @@ impl Class {
         self
     }
 
-    /// Changes class type
+    /// Changes class type, shorthand for setting [ClassKind] and modality / `data` / `value` [ClassModifier].
+    /// `inner`, `expect` and `actual` modifiers are kept.
     pub fn inheritance_modifier(mut self, inheritance_modifier: ClassInheritanceModifier) -> Self {
-        self.inheritance_modifier = inheritance_modifier;
+        let (kind, modifier) = inheritance_modifier.to_kind_and_modifier();
+        self.kind = kind;
+        self.modifiers.retain(|it| matches!(it, ClassModifier::Inner) || it.is_multiplatform());
+        if let Some(modifier) = modifier {
+            self.modifiers.insert(modifier);
+        }
         self
     }
 
@@ -140,13 +216,17 @@ impl Class {
         self
     }
 
-    /// Adds enum instance to this class. Enum instances in body will appear in order this method is called.
-    /// This method is only valid for enum classes. To change class type to enum please use [Class::inheritance_modifier].
-    pub fn enum_instance<NameLike: Into<Name>>(mut self, name: NameLike, arguments: Vec<Argument>) -> Self {
-        self.enum_instances.push(EnumInstance {
-            name: name.into(),
-            arguments,
-        });
+    /// Adds enum instance to this class, shorthand for [Class::enum_entry] with arguments only.
+    /// This method is only valid for enum classes. To change class type to enum please use [Class::kind].
+    pub fn enum_instance<NameLike: Into<Name>>(self, name: NameLike, arguments: Vec<Argument>) -> Self {
+        let entry = arguments.into_iter().fold(EnumEntry::new(name), EnumEntry::argument);
+        self.enum_entry(entry)
+    }
+
+    /// Adds [EnumEntry] to this class. Enum entries in body will appear in order this method is called.
+    /// This method is only valid for enum classes. To change class type to enum please use [Class::kind].
+    pub fn enum_entry(mut self, entry: EnumEntry) -> Self {
+        self.enum_entries.push(entry);
         self
     }
 
@@ -181,37 +261,243 @@ impl Class {
         self
     }
 
+    /// Sets [MemberOrder] of class body, members are rendered in insertion order by default.
+    /// Enum entries are always rendered first and companion object last.
+    /// Also applies to companion object members, unless [CompanionObject::member_order] is set.
+    pub fn member_order(mut self, member_order: MemberOrder) -> Self {
+        self.member_order = member_order;
+        self
+    }
+
+    /// Name of the class
+    pub(crate) fn name(&self) -> &Name {
+        &self.name
+    }
+
     /// Adds parent class / interface to this class.
-    pub fn inherits<TypeLike: Into<Type>>(mut self, parent_type: TypeLike) -> Self {
+    /// Accepts plain [Type] or [Supertype] with superclass constructor call or delegate.
+    pub fn inherits<SupertypeLike: Into<Supertype>>(mut self, parent_type: SupertypeLike) -> Self {
         self.parent_classes.push(parent_type.into());
         self
     }
 
+    /// Checks that the class would compile, nested classes are validated as well.
+    /// Rendering doesn't require validation, invalid classes are rendered as is.
+    ///
+    /// # Examples
+    /// ```
+    /// use kotlin_poet_rs::spec::{Class, ClassInheritanceModifier, PrimaryConstructor, Property, Type};
+    ///
+    /// let user_id = Class::new("UserId")
+    ///     .inheritance_modifier(ClassInheritanceModifier::Value)
+    ///     .primary_constructor(
+    ///         PrimaryConstructor::new()
+    ///             .property(Property::new("value", Type::string()))
+    ///     );
+    ///
+    /// assert!(user_id.validate().is_ok());
+    /// assert!(Class::new("Empty").inheritance_modifier(ClassInheritanceModifier::Value).validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        self.validate_modifiers()?;
+
+        if !self.enum_entries.is_empty() && self.kind != ClassKind::EnumClass {
+            return Err(ValidationError::new("Enum entries are only allowed in enum class"));
+        }
+
+        let superclass_calls = self.parent_classes.iter()
+            .filter(|it| matches!(it, Supertype::Class { .. }))
+            .count();
+        if superclass_calls > 1 {
+            return Err(ValidationError::new("Class can have only one superclass constructor call"));
+        }
+        if superclass_calls > 0 && self.kind == ClassKind::Interface {
+            return Err(ValidationError::new("Interface can't call superclass constructor"));
+        }
+        if self.kind == ClassKind::Interface
+            && self.parent_classes.iter().any(|it| matches!(it, Supertype::Delegated { .. })) {
+            return Err(ValidationError::new("Interface can't delegate implementation of its supertypes"));
+        }
+
+        if self.modifiers.contains(&ClassModifier::Value) {
+            let has_single_val = self.primary_constructor.as_ref()
+                .and_then(PrimaryConstructor::single_property)
+                .is_some_and(|property| !property.is_mutable());
+            if !has_single_val {
+                return Err(ValidationError::new(
+                    "Value class must have exactly one `val` property in primary constructor"
+                ));
+            }
+        }
+
+        if self.kind == ClassKind::AnnotationClass {
+            if let Some(primary_constructor) = &self.primary_constructor {
+                if primary_constructor.has_plain_parameters() {
+                    return Err(ValidationError::new(
+                        "Annotation class parameters must be declared as `val` properties"
+                    ));
+                }
+                if primary_constructor.properties().any(Property::is_mutable) {
+                    return Err(ValidationError::new(
+                        "Annotation class can't have `var` properties in primary constructor"
+                    ));
+                }
+                if let Some(invalid) = primary_constructor.parameter_types()
+                    .find(|parameter_type| !Class::is_annotation_parameter_type(parameter_type, true)) {
+                    return Err(ValidationError::new(&format!(
+                        "Type `{}` is not allowed as annotation class parameter",
+                        invalid.render_string()
+                    )));
+                }
+            }
+        }
+
+        // interface members are open, enum class may declare abstract members implemented by entries
+        let allows_abstract_members = matches!(self.kind, ClassKind::Interface | ClassKind::EnumClass)
+            || self.modifiers.contains(&ClassModifier::Abstract)
+            || self.modifiers.contains(&ClassModifier::Sealed);
+        ClassMemberNode::validate_all(&self.member_nodes, allows_abstract_members, &self.kind.render_string())?;
+
+        if let Some(companion_object) = &self.companion_object {
+            companion_object.validate()?;
+        }
+
+        Ok(())
+    }
+
+    fn validate_modifiers(&self) -> Result<(), ValidationError> {
+        for modifier in &self.modifiers {
+            let is_applicable = modifier.is_multiplatform() || match self.kind {
+                ClassKind::Class => !matches!(modifier, ClassModifier::Fun),
+                ClassKind::Interface => matches!(modifier, ClassModifier::Sealed | ClassModifier::Fun),
+                ClassKind::Object => matches!(modifier, ClassModifier::Data),
+                ClassKind::EnumClass | ClassKind::AnnotationClass => false,
+            };
+            if !is_applicable {
+                return Err(ValidationError::new(&format!(
+                    "Modifier `{}` is not applicable to `{}`",
+                    modifier.render_string(),
+                    self.kind.render_string()
+                )));
+            }
+        }
+
+        let incompatible = [
+            (ClassModifier::Data, ClassModifier::Value),
+            (ClassModifier::Data, ClassModifier::Open),
+            (ClassModifier::Data, ClassModifier::Abstract),
+            (ClassModifier::Data, ClassModifier::Sealed),
+            (ClassModifier::Data, ClassModifier::Inner),
+            (ClassModifier::Value, ClassModifier::Open),
+            (ClassModifier::Value, ClassModifier::Abstract),
+            (ClassModifier::Value, ClassModifier::Sealed),
+            (ClassModifier::Value, ClassModifier::Inner),
+            (ClassModifier::Fun, ClassModifier::Sealed),
+        ];
+        for (first, second) in incompatible {
+            if self.modifiers.contains(&first) && self.modifiers.contains(&second) {
+                return Err(ValidationError::new(&format!(
+                    "Modifiers `{}` and `{}` are incompatible",
+                    first.render_string(),
+                    second.render_string()
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Lack of modality modifier on plain class is rendered as `final`, data and value classes omit it for simplicity
+    fn is_implicitly_final(&self) -> bool {
+        self.kind == ClassKind::Class && !self.modifiers.iter().any(|it| {
+            it.is_modality() || matches!(it, ClassModifier::Data | ClassModifier::Value)
+        })
+    }
+
+    /// Checks that type is either primitive, `String`, `KClass`, enum, annotation or array of those.
+    /// Enums and annotations can't be told apart from other classes, so every class is accepted
+    /// except for known stdlib types which are neither enums nor annotations (`Any`, collections, etc.).
+    fn is_annotation_parameter_type(parameter_type: &Type, allow_array: bool) -> bool {
+        let Type::ClassLike(class_like) = parameter_type else {
+            return false;
+        };
+        if class_like.is_nullable() {
+            return false;
+        }
+
+        let raw_type = Type::from(class_like.type_name().clone());
+        let argument = class_like.generic_arguments().first();
+        if raw_type == Type::ClassLike(ClassLikeType::new(ClassLikeTypeName::from("kotlin.reflect.KClass"))) {
+            return true;
+        }
+        if raw_type == Type::ClassLike(ClassLikeType::new(ClassLikeTypeName::from("kotlin.Array"))) {
+            return allow_array && argument
+                .and_then(TypeProjection::projected_type)
+                .is_some_and(|element| Class::is_annotation_parameter_type(element, false));
+        }
+        if !class_like.generic_arguments().is_empty() {
+            return false;
+        }
+
+        let primitive_arrays = [
+            Type::boolean_array(), Type::char_array(), Type::byte_array(), Type::short_array(),
+            Type::int_array(), Type::long_array(), Type::float_array(), Type::double_array(),
+        ];
+        if primitive_arrays.contains(&raw_type) {
+            return allow_array;
+        }
+
+        let collections = Package::from(vec![Name::from("kotlin"), Name::from("collections")]);
+        let package = class_like.type_name().package();
+        if *package == collections || package.is_subpackage_of(&collections) {
+            return false;
+        }
+
+        let non_annotation_types = [
+            Type::any(), Type::nothing(), Type::unit(), Type::number(), Type::char_sequence(),
+            Type::throwable(), Type::exception(),
+            Type::from(ClassLikeTypeName::from("kotlin.Comparable")),
+            Type::from(ClassLikeTypeName::from("kotlin.Lazy")),
+            Type::from(ClassLikeTypeName::from("kotlin.Result")),
+            Type::from(ClassLikeTypeName::from("kotlin.Pair")),
+            Type::from(ClassLikeTypeName::from("kotlin.Triple")),
+        ];
+        !non_annotation_types.contains(&raw_type)
+    }
+
     mixin_annotation_mutators!();
     mixin_kdoc_mutators!();
 }
 
 impl RenderKotlin for Class {
     fn render_into(&self, block: &mut CodeBlock) {
-        block.push_renderable(&self.kdoc);
+        match &self.primary_constructor {
+            Some(primary_constructor) => block.push_renderable(&self.kdoc.with_tags(primary_constructor.kdoc_tags())),
+            None => block.push_renderable(&self.kdoc),
+        }
+        let jvm_inline = Annotation::jvm_inline();
+        if self.modifiers.contains(&ClassModifier::Value) && !self.annotation_slot.contains(jvm_inline.type_name()) {
+            jvm_inline.render_compact_into(block);
+            block.push_new_line();
+        }
         block.push_renderable(&self.annotation_slot);
 
         block.push_renderable(&self.visibility_modifier);
         block.push_space();
-        if self.is_inner {
-            block.push_static_atom(tokens::keyword::INNER);
+        for modifier in self.modifiers.iter().filter(|it| **it < ClassModifier::Open) {
+            block.push_renderable(modifier);
             block.push_space();
         }
-        block.push_renderable(&self.inheritance_modifier);
-        block.push_space();
-        if !matches!(
-            self.inheritance_modifier,
-            ClassInheritanceModifier::Interface |
-            ClassInheritanceModifier::Object
-        ) {
-            block.push_static_atom(tokens::keyword::CLASS);
+        if self.is_implicitly_final() {
+            block.push_static_atom(tokens::keyword::FINAL);
             block.push_space();
         }
+        for modifier in self.modifiers.iter().filter(|it| **it >= ClassModifier::Open) {
+            block.push_renderable(modifier);
+            block.push_space();
+        }
+        block.push_renderable(&self.kind);
+        block.push_space();
         block.push_renderable(&self.name);
         if !self.generic_parameters.is_empty() {
             block.push_angle_brackets(|code| {
@@ -247,29 +533,31 @@ impl RenderKotlin for Class {
         block.push_curly_brackets(|class_body_code| {
             class_body_code.push_new_line();
 
-            if !self.enum_instances.is_empty() {
-                for (inst_idx, instance) in self.enum_instances.iter().enumerate() {
-                    class_body_code.push_renderable(&instance.name);
-                    class_body_code.push_round_brackets(|arg_code| {
-                        arg_code.push_comma_separated(&instance.arguments);
-                    });
+            if !self.enum_entries.is_empty() {
+                for (entry_idx, entry) in self.enum_entries.iter().enumerate() {
+                    class_body_code.push_renderable(entry);
 
-                    if inst_idx != self.enum_instances.len() - 1 {
+                    if entry_idx != self.enum_entries.len() - 1 {
                         class_body_code.push_static_atom(tokens::COMMA);
                         class_body_code.push_new_line();
                     }
                 }
 
                 class_body_code.push_static_atom(tokens::SEMICOLON);
+                class_body_code.push_new_line();
             }
 
-            for node in &self.member_nodes {
-                class_body_code.push_renderable(node);
+            let mut members = self.member_nodes.iter()
+                .map(ClassMemberNode::as_member)
+                .collect::<Vec<_>>();
+            self.member_order.sort(&mut members);
+            for member in &members {
+                class_body_code.push_renderable(member);
                 class_body_code.push_new_line();
             }
 
             if let Some(companion_object) = &self.companion_object {
-                class_body_code.push_renderable(companion_object);
+                companion_object.render_ordered_into(class_body_code, &self.member_order);
                 class_body_code.push_new_line();
             }
         });
@@ -278,7 +566,8 @@ impl RenderKotlin for Class {
 
 #[cfg(test)]
 mod tests {
-    use crate::spec::{Parameter, GenericInvariance, PropertyGetter, PropertySetter, Type, ClassLikeTypeName, Package, KDoc};
+    use std::str::FromStr;
+    use crate::spec::{AnnotationClassTarget, AnnotationRetention, MemberInheritanceModifier, Parameter, GenericInvariance, PropertyGetter, PropertySetter, Type, ClassLikeTypeName, Package, KDoc};
     use super::*;
 
     #[test]
@@ -289,6 +578,162 @@ mod tests {
         assert_eq!(code, "public final class Person {\n\n}");
     }
 
+    #[test]
+    fn test_value_class() {
+        let class = Class::new(Name::from("UserId"))
+            .inheritance_modifier(ClassInheritanceModifier::Value)
+            .primary_constructor(
+                PrimaryConstructor::new()
+                    .property(Property::new(Name::from("value"), Type::string()))
+            );
+
+        assert_eq!(
+            class.render_string(),
+            "@kotlin.jvm.JvmInline\npublic value class UserId public constructor(public final val value: kotlin.String) {\n\n}"
+        );
+        assert_eq!(class.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_value_class_with_explicit_jvm_inline() {
+        let class = Class::new(Name::from("UserId"))
+            .modifier(ClassModifier::Value)
+            .annotation(Annotation::jvm_inline())
+            .primary_constructor(
+                PrimaryConstructor::new()
+                    .property(Property::new(Name::from("value"), Type::string()))
+            );
+
+        assert_eq!(
+            class.render_string(),
+            "@kotlin.jvm.JvmInline()\npublic value class UserId public constructor(public final val value: kotlin.String) {\n\n}"
+        );
+    }
+
+    #[test]
+    fn test_abstract_function_validation() {
+        let load = Function::new(Name::from("load"))
+            .inheritance_modifier(MemberInheritanceModifier::Abstract);
+
+        assert!(Class::new("Repository").kind(ClassKind::Interface).function(load.clone()).validate().is_ok());
+        assert!(Class::new("Repository").modifier(ClassModifier::Abstract).function(load.clone()).validate().is_ok());
+        assert!(Class::new("Repository").function(load.clone()).validate().is_err());
+        assert!(Class::new("Repository").kind(ClassKind::Object).function(load.clone()).validate().is_err());
+        assert!(
+            Class::new("Repository")
+                .modifier(ClassModifier::Abstract)
+                .companion_object(CompanionObject::new().function(load))
+                .validate().is_err()
+        );
+        assert!(
+            Class::new("Repository")
+                .function(Function::new(Name::from("to")).infix(true))
+                .validate().is_err()
+        );
+    }
+
+    #[test]
+    fn test_value_class_validation() {
+        let mutable = Class::new(Name::from("UserId"))
+            .inheritance_modifier(ClassInheritanceModifier::Value)
+            .primary_constructor(
+                PrimaryConstructor::new()
+                    .property(Property::new(Name::from("value"), Type::string()).mutable(true))
+            );
+        assert!(mutable.validate().is_err());
+
+        let two_properties = Class::new(Name::from("UserId"))
+            .inheritance_modifier(ClassInheritanceModifier::Value)
+            .primary_constructor(
+                PrimaryConstructor::new()
+                    .property(Property::new(Name::from("value"), Type::string()))
+                    .property(Property::new(Name::from("other"), Type::string()))
+            );
+        assert!(two_properties.validate().is_err());
+
+        let nested = Class::new(Name::from("Outer"))
+            .subclass(Class::new(Name::from("Inner")).inheritance_modifier(ClassInheritanceModifier::Value));
+        assert!(nested.validate().is_err());
+    }
+
+    #[test]
+    fn test_annotation_class() {
+        let class = Class::new(Name::from("Qualifier"))
+            .inheritance_modifier(ClassInheritanceModifier::Annotation)
+            .annotation(Annotation::annotation_target([AnnotationClassTarget::Field]))
+            .annotation(Annotation::retention(AnnotationRetention::Binary))
+            .annotation(Annotation::must_be_documented())
+            .primary_constructor(
+                PrimaryConstructor::new()
+                    .property(Property::new(Name::from("name"), Type::string()))
+            );
+
+        assert_eq!(
+            class.render_string(),
+            "@kotlin.annotation.Target(kotlin.annotation.AnnotationTarget.FIELD)\n\
+            @kotlin.annotation.Retention(kotlin.annotation.AnnotationRetention.BINARY)\n\
+            @kotlin.annotation.MustBeDocumented()\n\
+            public annotation class Qualifier public constructor(public final val name: kotlin.String) {\n\n}"
+        );
+        assert_eq!(class.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_annotation_class_validation() {
+        let annotation_class = |parameter_type: Type| Class::new(Name::from("Marker"))
+            .inheritance_modifier(ClassInheritanceModifier::Annotation)
+            .primary_constructor(
+                PrimaryConstructor::new()
+                    .property(Property::new(Name::from("value"), parameter_type))
+            );
+
+        let valid = [
+            Type::int(),
+            Type::uint(),
+            Type::int_array(),
+            Type::array(Type::string()),
+            Type::array(ClassLikeTypeName::from("a.Kind")),
+            Type::from_str("kotlin.reflect.KClass<*>").unwrap(),
+            Type::from(ClassLikeTypeName::from("a.Kind")),
+            Type::from(ClassLikeTypeName::from("kotlin.DeprecationLevel")),
+            Type::from(ClassLikeTypeName::from("kotlin.ReplaceWith")),
+            Type::array(ClassLikeTypeName::from("kotlin.annotation.AnnotationTarget")),
+        ];
+        for parameter_type in valid {
+            assert_eq!(annotation_class(parameter_type).validate(), Ok(()));
+        }
+
+        let invalid = [
+            Type::string().nullable(true),
+            Type::list(Type::string()),
+            Type::array(Type::int_array()),
+            Type::generic("T"),
+            Type::any(),
+            Type::from(ClassLikeTypeName::from("kotlin.collections.List")),
+            Type::from(ClassLikeTypeName::from("kotlin.Pair")),
+            Type::from_str("() -> kotlin.Unit").unwrap(),
+        ];
+        for parameter_type in invalid {
+            assert!(annotation_class(parameter_type).validate().is_err());
+        }
+
+        let mutable = Class::new(Name::from("Marker"))
+            .inheritance_modifier(ClassInheritanceModifier::Annotation)
+            .primary_constructor(
+                PrimaryConstructor::new()
+                    .property(Property::new(Name::from("value"), Type::int()).mutable(true))
+            );
+        assert!(mutable.validate().is_err());
+
+        let plain_parameter = Class::new(Name::from("Marker"))
+            .inheritance_modifier(ClassInheritanceModifier::Annotation)
+            .primary_constructor(
+                PrimaryConstructor::new()
+                    .parameter(Parameter::new(Name::from("value"), Type::int()))
+            );
+        assert!(plain_parameter.validate().is_err());
+    }
+
     #[test]
     fn test_class_with_kdoc() {
         let class = Class::new(Name::from("Person"))
@@ -304,6 +749,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_class_with_constructor_parameter_kdoc() {
+        let class = Class::new(Name::from("Person"))
+            .kdoc("Person record")
+            .primary_constructor(
+                PrimaryConstructor::new()
+                    .property(Property::new(Name::from("name"), Type::string()).kdoc("full name"))
+                    .parameter(Parameter::new(Name::from("age"), Type::int()).kdoc("age in years"))
+                    .parameter(Parameter::new(Name::from("nickname"), Type::string()))
+            );
+
+        assert_eq!(
+            class.render_string(),
+            "/**\n * Person record\n * @property name full name\n * @param age age in years\n */\n\
+            public final class Person public constructor(public final val name: kotlin.String, age: kotlin.Int, nickname: kotlin.String) {\n\n}"
+        );
+    }
+
     #[test]
     fn test_class_with_property() {
         let property = Property::new(
@@ -347,7 +810,30 @@ mod tests {
 
         assert_eq!(
             code,
-            "public enum class Person {\n\n    Alex(23),\n    Vova(23);}"
+            "public enum class Person {\n\n    Alex(23),\n    Vova(23);\n}"
+        );
+    }
+
+    #[test]
+    fn test_enum_entries() {
+        let class = Class::new(Name::from("Color"))
+            .kind(ClassKind::EnumClass)
+            .enum_entry(
+                EnumEntry::new("Red")
+                    .kdoc("Red color")
+                    .function(
+                        Function::new("describe")
+                            .overrides(true)
+                            .returns(Type::string())
+                            .body(CodeBlock::statement("return \"red\""))
+                    )
+            )
+            .enum_entry(EnumEntry::new("Green"))
+            .property(Property::new(Name::from("hex"), Type::string()).initializer("\"\""));
+
+        assert_eq!(
+            class.render_string(),
+            "public enum class Color {\n\n    /**\n     * Red color\n     */\n    Red {\n        public override fun describe(): kotlin.String {\n            return \"red\"\n        }\n    },\n    Green;\n    public final val hex: kotlin.String = \"\"\n}"
         );
     }
 
@@ -507,7 +993,154 @@ mod tests {
 
         assert_eq!(
             class.render_string(),
-            "public final class Person {\n\n    public inner abstract class InnerPerson {\n\n    }\n}"
+            "public final class Person {\n\n    public abstract inner class InnerPerson {\n\n    }\n}"
+        );
+    }
+
+    #[test]
+    fn test_combined_modifiers() {
+        let sealed_interface = Class::new(Name::from("Event"))
+            .kind(ClassKind::Interface)
+            .modifier(ClassModifier::Sealed);
+        assert_eq!(sealed_interface.render_string(), "public sealed interface Event {\n\n}");
+
+        let fun_interface = Class::new(Name::from("Listener"))
+            .kind(ClassKind::Interface)
+            .modifier(ClassModifier::Fun);
+        assert_eq!(fun_interface.render_string(), "public fun interface Listener {\n\n}");
+
+        let data_object = Class::new(Name::from("Empty"))
+            .kind(ClassKind::Object)
+            .modifier(ClassModifier::Data);
+        assert_eq!(data_object.render_string(), "public data object Empty {\n\n}");
+
+        let expect_class = Class::new(Name::from("Platform"))
+            .modifier(ClassModifier::Abstract)
+            .modifier(ClassModifier::Expect)
+            .inner(true);
+        assert_eq!(expect_class.render_string(), "public expect abstract inner class Platform {\n\n}");
+
+        let actual_enum = Class::new(Name::from("Color"))
+            .kind(ClassKind::EnumClass)
+            .modifier(ClassModifier::Actual);
+        assert_eq!(actual_enum.render_string(), "public actual enum class Color {\n\n}");
+
+        for class in [sealed_interface, fun_interface, data_object, expect_class, actual_enum] {
+            assert_eq!(class.validate(), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_modality_is_exclusive() {
+        let class = Class::new(Name::from("Person"))
+            .modifier(ClassModifier::Open)
+            .modifier(ClassModifier::Sealed);
+
+        assert_eq!(class.render_string(), "public sealed class Person {\n\n}");
+    }
+
+    #[test]
+    fn test_inheritance_modifier_keeps_inner() {
+        let class = Class::new(Name::from("Person"))
+            .inner(true)
+            .modifier(ClassModifier::Data)
+            .inheritance_modifier(ClassInheritanceModifier::Open);
+
+        assert_eq!(class.render_string(), "public open inner class Person {\n\n}");
+    }
+
+    #[test]
+    fn test_invalid_modifier_combinations() {
+        let invalid = [
+            Class::new(Name::from("A")).kind(ClassKind::Interface).modifier(ClassModifier::Data),
+            Class::new(Name::from("A")).kind(ClassKind::Interface)
+                .modifier(ClassModifier::Fun).modifier(ClassModifier::Sealed),
+            Class::new(Name::from("A")).modifier(ClassModifier::Fun),
+            Class::new(Name::from("A")).kind(ClassKind::Object).modifier(ClassModifier::Open),
+            Class::new(Name::from("A")).kind(ClassKind::EnumClass).inner(true),
+            Class::new(Name::from("A")).modifier(ClassModifier::Data).modifier(ClassModifier::Abstract),
+            Class::new(Name::from("A")).modifier(ClassModifier::Data).inner(true),
+            Class::new(Name::from("A")).enum_instance("B", vec![]),
+        ];
+        for class in invalid {
+            assert!(class.validate().is_err(), "{} should be invalid", class.render_string());
+        }
+    }
+
+    #[test]
+    fn test_member_order_by_conventions() {
+        let class = Class::new(Name::from("Person"))
+            .companion_object(CompanionObject::new())
+            .subclass(Class::new(Name::from("Nested")))
+            .function(Function::new(Name::from("greet")))
+            .secondary_constructor(SecondaryConstructor::new())
+            .property(Property::new(Name::from("name"), Type::string()))
+            .init(CodeBlock::statement("println(name)"))
+            .property(Property::new(Name::from("age"), Type::int()))
+            .member_order(MemberOrder::KotlinConventions);
+
+        assert_eq!(
+            class.render_string(),
+            "public final class Person {\n\n\
+            \x20   public final val name: kotlin.String\n\
+            \x20   init{\n        println(name)\n    }\n\
+            \x20   public final val age: kotlin.Int\n\
+            \x20   public constructor() : this() {\n    }\n\
+            \x20   public fun greet(): kotlin.Unit \n\
+            \x20   public final class Nested {\n\n    }\n\
+            \x20   public companion object {\n    }\n}"
+        );
+    }
+
+    #[test]
+    fn test_member_order_custom() {
+        let class = Class::new(Name::from("Person"))
+            .function(Function::new(Name::from("b")))
+            .property(Property::new(Name::from("c"), Type::string()))
+            .function(Function::new(Name::from("a")))
+            .member_order(MemberOrder::custom(|a, b| b.name().cmp(&a.name())));
+
+        assert_eq!(
+            class.render_string(),
+            "public final class Person {\n\n    public final val c: kotlin.String\n    public fun b(): kotlin.Unit \n    public fun a(): kotlin.Unit \n}"
+        );
+    }
+
+    #[test]
+    fn test_member_order_applies_to_companion_object() {
+        let companion_object = CompanionObject::new()
+            .function(Function::new(Name::from("create")))
+            .property(Property::new(Name::from("DEFAULT"), Type::string()));
+        let class = Class::new(Name::from("Person"))
+            .companion_object(companion_object.clone())
+            .member_order(MemberOrder::KotlinConventions);
+        let insertion_ordered_companion = Class::new(Name::from("Person"))
+            .companion_object(companion_object.member_order(MemberOrder::Insertion))
+            .member_order(MemberOrder::KotlinConventions);
+
+        assert_eq!(
+            class.render_string(),
+            "public final class Person {\n\n    public companion object {\n        public final val DEFAULT: kotlin.String\n        public fun create(): kotlin.Unit \n    }\n}"
+        );
+        assert_eq!(
+            insertion_ordered_companion.render_string(),
+            "public final class Person {\n\n    public companion object {\n        public fun create(): kotlin.Unit \n        public final val DEFAULT: kotlin.String\n    }\n}"
+        );
+    }
+
+    #[test]
+    fn test_member_order_by_signature() {
+        let class = Class::new(Name::from("Parser"))
+            .function(Function::new(Name::from("parse")).parameter(Parameter::new("value", Type::string())))
+            .property(Property::new(Name::from("strict"), Type::boolean()))
+            .function(Function::new(Name::from("parse")))
+            .member_order(MemberOrder::custom(|a, b| {
+                a.kind().cmp(&b.kind()).then_with(|| a.signature().len().cmp(&b.signature().len()))
+            }));
+
+        assert_eq!(
+            class.render_string(),
+            "public final class Parser {\n\n    public final val strict: kotlin.Boolean\n    public fun parse(): kotlin.Unit \n    public fun parse(value: kotlin.String): kotlin.Unit \n}"
         );
     }
 
@@ -555,6 +1188,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_supertypes() {
+        let class = Class::new(Name::from("CachedRepository"))
+            .generic_parameter(
+                GenericParameter::new(Name::from("T"))
+                    .type_boundary(Type::any())
+            )
+            .primary_constructor(
+                PrimaryConstructor::new()
+                    .parameter(Parameter::new(Name::from("delegate"), ClassLikeTypeName::from("a.Repository")))
+            )
+            .inherits(Supertype::class(
+                ClassLikeTypeName::from("a.Base"),
+                vec![Argument::new_positional(CodeBlock::atom("delegate"))],
+            ))
+            .inherits(Supertype::delegated(ClassLikeTypeName::from("a.Repository"), CodeBlock::atom("delegate")))
+            .inherits(Type::comparable(Type::generic("T")));
+
+        assert_eq!(
+            class.render_string(),
+            "public final class CachedRepository<T> public constructor(delegate: a.Repository): a.Base(delegate), a.Repository by delegate, kotlin.Comparable<T> where T: kotlin.Any {\n\n}"
+        );
+        assert_eq!(class.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_supertypes_validation() {
+        let two_superclasses = Class::new(Name::from("A"))
+            .inherits(Supertype::class(ClassLikeTypeName::from("a.B"), vec![]))
+            .inherits(Supertype::class(ClassLikeTypeName::from("a.C"), vec![]));
+        assert!(two_superclasses.validate().is_err());
+
+        let interface = Class::new(Name::from("A"))
+            .kind(ClassKind::Interface)
+            .inherits(Supertype::class(ClassLikeTypeName::from("a.B"), vec![]));
+        assert!(interface.validate().is_err());
+
+        let delegating_interface = Class::new(Name::from("A"))
+            .kind(ClassKind::Interface)
+            .inherits(Supertype::delegated(ClassLikeTypeName::from("a.B"), CodeBlock::atom("delegate")));
+        assert!(delegating_interface.validate().is_err());
+
+        let extending_interface = Class::new(Name::from("A"))
+            .kind(ClassKind::Interface)
+            .inherits(ClassLikeTypeName::from("a.B"));
+        assert_eq!(extending_interface.validate(), Ok(()));
+    }
+
     #[test]
     fn test_generic_class_with_boundaries() {
         let class = Class::new(Name::from("Box"))
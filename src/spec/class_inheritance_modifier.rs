@@ -1,11 +1,14 @@
 use crate::io::RenderKotlin;
-use crate::spec::CodeBlock;
+use crate::spec::{ClassKind, ClassModifier, CodeBlock};
 use crate::tokens;
 
 /// Inheritance modifiers applicable to class-like entities.
 /// Enables converting class to interface, enum e.t.c.
 /// Defaults to [ClassInheritanceModifier::Final], lack of inheritance modifier is represented as default.
 ///
+/// This is a shorthand for the most common combinations of [ClassKind] and [ClassModifier],
+/// see [crate::spec::Class::kind] and [crate::spec::Class::modifier] for the full declaration model.
+///
 /// Class / File members use [crate::spec::MemberInheritanceModifier] instead.
 #[derive(Debug, Clone, Default)]
 pub enum ClassInheritanceModifier {
@@ -26,6 +29,29 @@ pub enum ClassInheritanceModifier {
     Enum,
     /// Denotes that class-like entity is data, for simplicity final keyword is omitted
     Data,
+    /// Denotes [value class](https://kotlinlang.org/docs/inline-classes.html), `@JvmInline` annotation is added automatically
+    Value,
+    /// Denotes [annotation class](https://kotlinlang.org/docs/annotations.html#declaring-annotations),
+    /// see [crate::spec::Annotation::annotation_target] and [crate::spec::Annotation::retention] for meta-annotations
+    Annotation,
+}
+
+impl ClassInheritanceModifier {
+    /// Splits shorthand into [ClassKind] and optional [ClassModifier]
+    pub(crate) fn to_kind_and_modifier(&self) -> (ClassKind, Option<ClassModifier>) {
+        match self {
+            ClassInheritanceModifier::Open => (ClassKind::Class, Some(ClassModifier::Open)),
+            ClassInheritanceModifier::Final => (ClassKind::Class, None),
+            ClassInheritanceModifier::Interface => (ClassKind::Interface, None),
+            ClassInheritanceModifier::Abstract => (ClassKind::Class, Some(ClassModifier::Abstract)),
+            ClassInheritanceModifier::Sealed => (ClassKind::Class, Some(ClassModifier::Sealed)),
+            ClassInheritanceModifier::Object => (ClassKind::Object, None),
+            ClassInheritanceModifier::Enum => (ClassKind::EnumClass, None),
+            ClassInheritanceModifier::Data => (ClassKind::Class, Some(ClassModifier::Data)),
+            ClassInheritanceModifier::Value => (ClassKind::Class, Some(ClassModifier::Value)),
+            ClassInheritanceModifier::Annotation => (ClassKind::AnnotationClass, None),
+        }
+    }
 }
 
 impl RenderKotlin for ClassInheritanceModifier {
@@ -38,7 +64,9 @@ impl RenderKotlin for ClassInheritanceModifier {
             ClassInheritanceModifier::Interface => tokens::keyword::INTERFACE,
             ClassInheritanceModifier::Object => tokens::keyword::OBJECT,
             ClassInheritanceModifier::Enum => tokens::keyword::ENUM,
-            ClassInheritanceModifier::Data => tokens::keyword::DATA
+            ClassInheritanceModifier::Data => tokens::keyword::DATA,
+            ClassInheritanceModifier::Value => tokens::keyword::VALUE,
+            ClassInheritanceModifier::Annotation => tokens::keyword::ANNOTATION,
         };
 
         block.push_atom(text);
@@ -56,5 +84,6 @@ mod tests {
         assert_eq!(ClassInheritanceModifier::Final.render_string(), "final");
         assert_eq!(ClassInheritanceModifier::Abstract.render_string(), "abstract");
         assert_eq!(ClassInheritanceModifier::Sealed.render_string(), "sealed");
+        assert_eq!(ClassInheritanceModifier::Value.render_string(), "value");
     }
 }
\ No newline at end of file
@@ -0,0 +1,56 @@
+use crate::io::RenderKotlin;
+use crate::spec::CodeBlock;
+use crate::tokens;
+
+/// Kind of class-like declaration, decides which keywords introduce it.
+/// Defaults to [ClassKind::Class].
+///
+/// Modifiers such as `sealed` or `data` are represented separately via [crate::spec::ClassModifier].
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Default)]
+pub enum ClassKind {
+    /// Plain `class`
+    #[default]
+    Class,
+    /// `interface`
+    Interface,
+    /// Standalone `object`, for companion objects see [crate::spec::CompanionObject]
+    Object,
+    /// `enum class`
+    EnumClass,
+    /// `annotation class`
+    AnnotationClass,
+}
+
+impl RenderKotlin for ClassKind {
+    fn render_into(&self, block: &mut CodeBlock) {
+        match self {
+            ClassKind::Class => block.push_static_atom(tokens::keyword::CLASS),
+            ClassKind::Interface => block.push_static_atom(tokens::keyword::INTERFACE),
+            ClassKind::Object => block.push_static_atom(tokens::keyword::OBJECT),
+            ClassKind::EnumClass => {
+                block.push_static_atom(tokens::keyword::ENUM);
+                block.push_space();
+                block.push_static_atom(tokens::keyword::CLASS);
+            }
+            ClassKind::AnnotationClass => {
+                block.push_static_atom(tokens::keyword::ANNOTATION);
+                block.push_space();
+                block.push_static_atom(tokens::keyword::CLASS);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::RenderKotlin;
+    use crate::spec::ClassKind;
+
+    #[test]
+    fn test_render() {
+        assert_eq!(ClassKind::Class.render_string(), "class");
+        assert_eq!(ClassKind::Interface.render_string(), "interface");
+        assert_eq!(ClassKind::EnumClass.render_string(), "enum class");
+        assert_eq!(ClassKind::AnnotationClass.render_string(), "annotation class");
+    }
+}
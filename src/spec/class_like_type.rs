@@ -1,9 +1,10 @@
+use std::cmp::Ordering;
 use std::str::FromStr;
 
 use crate::io::RenderKotlin;
-use crate::spec::{ClassLikeTypeName, CodeBlock, Type};
+use crate::spec::{ClassLikeTypeName, CodeBlock, Package, Type, TypeProjection};
 use crate::tokens;
-use crate::util::{SemanticConversionError, yolo_from_str};
+use crate::util::{compare_rendered, SemanticConversionError, yolo_from_str};
 
 /// Represents a class like type, such as a class, interface, or enum.
 /// This type can be nullable and can have generic arguments and nullability mark.
@@ -28,11 +29,27 @@ use crate::util::{SemanticConversionError, yolo_from_str};
 ///     class_like_type
 /// )
 /// ```
-#[derive(PartialEq, Debug, Clone)]
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
 pub struct ClassLikeType {
     type_name: ClassLikeTypeName,
     nullable: bool,
-    generic_arguments: Vec<Type>,
+    generic_arguments: Vec<TypeProjection>,
+}
+
+/// Types are ordered as they render
+impl Ord for ClassLikeType {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare_rendered(self, other)
+            .then_with(|| self.type_name.cmp(&other.type_name))
+            .then_with(|| self.generic_arguments.cmp(&other.generic_arguments))
+            .then_with(|| self.nullable.cmp(&other.nullable))
+    }
+}
+
+impl PartialOrd for ClassLikeType {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
 impl ClassLikeType {
@@ -50,11 +67,35 @@ impl ClassLikeType {
         self
     }
 
-    /// Adds a generic argument to the type
-    pub fn generic_argument<TypeLike: Into<Type>>(mut self, parameter: TypeLike) -> ClassLikeType {
+    /// Checks if the type is marked as nullable
+    pub fn is_nullable(&self) -> bool {
+        self.nullable
+    }
+
+    /// Adds a generic argument to the type.
+    /// Accepts plain [Type] as invariant argument or any other [TypeProjection], e.g. `*` or `out T`.
+    pub fn generic_argument<TypeProjectionLike: Into<TypeProjection>>(mut self, parameter: TypeProjectionLike) -> ClassLikeType {
         self.generic_arguments.push(parameter.into());
         self
     }
+
+    /// Name of the class
+    pub fn type_name(&self) -> &ClassLikeTypeName {
+        &self.type_name
+    }
+
+    /// Generic arguments in order of declaration
+    pub fn generic_arguments(&self) -> &[TypeProjection] {
+        &self.generic_arguments
+    }
+
+    /// Replaces every generic argument's projected type with the result of [transform]
+    pub(crate) fn map_types<F: FnMut(Type) -> Type>(mut self, transform: &mut F) -> ClassLikeType {
+        self.generic_arguments = self.generic_arguments.into_iter()
+            .map(|argument| argument.map_type(transform))
+            .collect();
+        self
+    }
 }
 
 impl RenderKotlin for ClassLikeType {
@@ -86,43 +127,22 @@ impl FromStr for ClassLikeType {
     type Err = SemanticConversionError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut is_nullable = false;
-        let nullable_clean_buf = if s.ends_with(tokens::QUESTION_MARK) {
-            is_nullable = true;
-            s.trim_end_matches(tokens::QUESTION_MARK)
-        } else {
-            s
-        };
-
-        let mut generics: Vec<Type> = Vec::new();
-        let final_buf = if nullable_clean_buf.ends_with(tokens::ANGLE_BRACKET_RIGHT) {
-            let parts = nullable_clean_buf.split(tokens::ANGLE_BRACKET_LEFT).collect::<Vec<&str>>();
-            if parts.len() != 2 {
-                return Err(SemanticConversionError::new(
-                    format!("Invalid class like type with generic like tokens: {}", s).as_str()
-                ));
-            }
-            let generic_sub_buf = parts[1].trim_end_matches(tokens::ANGLE_BRACKET_RIGHT);
-            for generic_buf in generic_sub_buf.split(tokens::COMMA) {
-                let generic_buf_type = Type::from_str(generic_buf)?;
-                generics.push(generic_buf_type);
-            }
-
-            parts[0]
-        } else {
-            nullable_clean_buf
-        };
-
-
-        let mut class_like_type = ClassLikeType::new(
-            ClassLikeTypeName::from_str(final_buf)?
-        ).nullable(is_nullable);
-
-        for generic in generics {
-            class_like_type = class_like_type.generic_argument(generic);
+        let parsed = Type::from_str(s)?;
+        let is_nullable = parsed.is_nullable();
+
+        // Short names like `T` are parsed as generics, but here they could only be class names
+        match parsed.nullable(false) {
+            Type::ClassLike(class_like_type) => Ok(class_like_type.nullable(is_nullable)),
+            Type::Generic(name) => Ok(
+                ClassLikeType::new(ClassLikeTypeName::top_level(Package::root(), name))
+                    .nullable(is_nullable)
+            ),
+            _ => Err(
+                SemanticConversionError::new(
+                    format!("`{}` is not a class like type", s).as_str()
+                )
+            ),
         }
-
-        Ok(class_like_type)
     }
 }
 
@@ -131,7 +151,7 @@ mod tests {
     use std::str::FromStr;
 
     use crate::io::RenderKotlin;
-    use crate::spec::{ClassLikeType, ClassLikeTypeName, Type};
+    use crate::spec::{ClassLikeType, ClassLikeTypeName, Type, TypeProjection};
     use crate::spec::{Name, Package};
 
     #[test]
@@ -258,6 +278,57 @@ mod tests {
         assert_eq!(parsed, expected);
     }
 
+    #[test]
+    fn test_from_str_nested_generic() {
+        let parsed = ClassLikeType::from_str("io/github/Outer.Inner<T>").unwrap();
+        let expected = ClassLikeType::new(
+            ClassLikeTypeName::nested(
+                Package::from_str("io.github").unwrap(),
+                vec![Name::from("Outer"), Name::from("Inner")],
+            )
+        ).generic_argument(Type::generic("T"));
+
+        assert_eq!(parsed, expected);
+        assert_eq!(parsed.render_string(), "io.github.Outer.Inner<T>");
+    }
+
+    #[test]
+    fn test_from_str_deeply_nested_generic() {
+        let parsed = ClassLikeType::from_str("a.Box<a.Box<kotlin.Int>, T>").unwrap();
+        let expected = ClassLikeType::new(ClassLikeTypeName::from_str("a.Box").unwrap())
+            .generic_argument(ClassLikeType::new(ClassLikeTypeName::from_str("a.Box").unwrap())
+                .generic_argument(Type::int()))
+            .generic_argument(Type::generic("T"));
+
+        assert_eq!(parsed, expected);
+    }
+
+    #[test]
+    fn test_from_str_projections() {
+        let parsed = ClassLikeType::from_str("a.Box<*, in T, out kotlin.Any>").unwrap();
+        let expected = ClassLikeType::new(ClassLikeTypeName::from_str("a.Box").unwrap())
+            .generic_argument(TypeProjection::star())
+            .generic_argument(TypeProjection::contravariant(Type::generic("T")))
+            .generic_argument(TypeProjection::covariant(Type::any()));
+
+        assert_eq!(parsed, expected);
+        assert_eq!(parsed.render_string(), "a.Box<*, in T, out kotlin.Any>");
+    }
+
+    #[test]
+    fn test_from_str_function_type() {
+        assert!(ClassLikeType::from_str("() -> kotlin.Unit").is_err());
+    }
+
+    #[test]
+    fn test_from_str_short_nullable() {
+        let parsed = ClassLikeType::from_str("T?").unwrap();
+        let expected = ClassLikeType::new(ClassLikeTypeName::from_str("T").unwrap())
+            .nullable(true);
+
+        assert_eq!(parsed, expected);
+    }
+
     #[test]
     fn test_from_str_class() {
         let parsed = ClassLikeType::from_str("io.github.lexadiky.Class<kotlin.Int>?").unwrap();
@@ -1,20 +1,38 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
 use crate::io::RenderKotlin;
 use crate::spec::{CodeBlock, Name, Package};
 use crate::tokens;
-use crate::util::{SemanticConversionError, yolo_from_str};
+use crate::util::{compare_rendered, SemanticConversionError, yolo_from_str};
 
 /// Represents a class-like type name.
 ///
 /// This struct does not support generics, nullability or any other possible type 'modifiers'.
 /// You can use [ClassLikeType] instead.
-#[derive(PartialEq, Debug, Clone)]
+///
+/// Names are ordered as they render, names rendering the same way are ordered by package.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
 pub struct ClassLikeTypeName {
     pub(crate) package: Package,
     names: Vec<Name>,
 }
 
+impl Ord for ClassLikeTypeName {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare_rendered(self, other)
+            .then_with(|| self.package.cmp(&other.package))
+            .then_with(|| self.names.cmp(&other.names))
+    }
+}
+
+impl PartialOrd for ClassLikeTypeName {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl ClassLikeTypeName {
     /// Creates top level class name, for example `com.example.MyClass`
     pub fn top_level<NameLike: Into<Name>, PackageLike: Into<Package>>(
@@ -34,43 +52,155 @@ impl ClassLikeTypeName {
             names,
         }
     }
+
+    /// Package this class is declared in
+    pub fn package(&self) -> &Package {
+        &self.package
+    }
+
+    /// Simple (innermost) name of the class, for example `InnerClass` for `com.example.MyClass.InnerClass`
+    pub fn simple_name(&self) -> &Name {
+        self.names.last()
+            .expect("ClassLikeTypeName must contain at least one name")
+    }
+
+    /// All class names from outermost to innermost, for example `[MyClass, InnerClass]` for `com.example.MyClass.InnerClass`
+    pub fn simple_names(&self) -> &[Name] {
+        &self.names
+    }
+
+    /// Returns enclosing class name, for example `com.example.MyClass` for `com.example.MyClass.InnerClass`.
+    /// Returns [None] for top level classes.
+    pub fn enclosing_class(&self) -> Option<ClassLikeTypeName> {
+        if self.names.len() < 2 {
+            return None;
+        }
+
+        Some(ClassLikeTypeName {
+            package: self.package.clone(),
+            names: self.names[..self.names.len() - 1].to_vec(),
+        })
+    }
+
+    /// Returns name of class nested in this class, for example `com.example.MyClass.Builder` for `com.example.MyClass`
+    pub fn nested_class<NameLike: Into<Name>>(&self, name: NameLike) -> ClassLikeTypeName {
+        let mut names = self.names.clone();
+        names.push(name.into());
+        ClassLikeTypeName {
+            package: self.package.clone(),
+            names,
+        }
+    }
+
+    /// Returns name of class declared next to this class, for example `com.example.Other` for `com.example.MyClass`.
+    /// For nested classes peer is declared in the same enclosing class.
+    pub fn peer_class<NameLike: Into<Name>>(&self, name: NameLike) -> ClassLikeTypeName {
+        let mut names = self.names.clone();
+        names.pop();
+        names.push(name.into());
+        ClassLikeTypeName {
+            package: self.package.clone(),
+            names,
+        }
+    }
+
+    /// Returns fully qualified name of the class, for example `com.example.MyClass.InnerClass`
+    pub fn canonical_name(&self) -> String {
+        self.render_string()
+    }
+}
+
+impl Display for ClassLikeTypeName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.canonical_name().as_str())
+    }
 }
 
 yolo_from_str!(ClassLikeTypeName);
+/// Parses [ClassLikeTypeName] from [&str].
+///
+/// Supports two notations:
+/// - Kotlin metadata form `a/b/Outer.Inner`, where `/` separates package segments and `.` separates nested classes.
+/// - Dotted form `a.b.Outer`, where the last segment is always treated as top level class name.
+///   If you need to parse nested classes from dotted form use [ClassLikeTypeName::parse_capitalized].
 impl FromStr for ClassLikeTypeName {
     type Err = SemanticConversionError;
 
-    #[allow(clippy::comparison_chain)]
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts: Vec<&str> = s.split('.').collect();
-        if parts.len() > 1 {
+        if let Some((package_buf, names_buf)) = s.rsplit_once(tokens::SLASH) {
             let mut package_parts = Vec::new();
-            for part in &parts[0..parts.len() - 1] {
-                package_parts.push(Name::from_str(part)?)
+            let mut offset = 0;
+            for part in package_buf.split(tokens::SLASH) {
+                if part.is_empty() {
+                    return Err(SemanticConversionError::new_at("Empty package segment", offset));
+                }
+                package_parts.push(Name::from_str(part)?);
+                offset += part.len() + tokens::SLASH.len();
             }
 
-            let package = Package::from(package_parts);
-            let name = Name::from_str(parts[parts.len() - 1])?;
-
-            Ok(ClassLikeTypeName::top_level(
-                package,
-                name,
-            )
-            )
-        } else if parts.len() == 1 {
-            Ok(
-                ClassLikeTypeName::top_level(
-                    Package::from(vec![]),
-                    Name::from(parts[0]),
-                )
-            )
-        } else {
-            Err(
-                SemanticConversionError::new(
-                    format!("Can't convert {s} to ClassLikeTypeName").as_str()
+            return Ok(
+                ClassLikeTypeName::nested(
+                    Package::from(package_parts),
+                    Self::parse_names(names_buf)?,
                 )
-            )
+            );
         }
+
+        let parts: Vec<&str> = s.split(tokens::DOT).collect();
+        let (package_parts, name) = parts.split_at(parts.len() - 1);
+        let package = Package::from(
+            package_parts.iter()
+                .map(|part| Name::from_str(part))
+                .collect::<Result<Vec<_>, SemanticConversionError>>()?
+        );
+
+        Ok(ClassLikeTypeName::top_level(package, Name::from_str(name[0])?))
+    }
+}
+
+impl ClassLikeTypeName {
+    /// Parses dotted class name using capitalization heuristic.
+    /// Segments before the first capitalized one are treated as package, the rest as (nested) class names.
+    ///
+    /// If none of the segments is capitalized, behaves like [ClassLikeTypeName::from_str].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use kotlin_poet_rs::spec::{ClassLikeTypeName, Name, Package};
+    ///
+    /// assert_eq!(
+    ///     ClassLikeTypeName::parse_capitalized("a.b.Outer.Inner").unwrap(),
+    ///     ClassLikeTypeName::nested(
+    ///         Package::from_str("a.b").unwrap(),
+    ///         vec![Name::from("Outer"), Name::from("Inner")]
+    ///     )
+    /// );
+    /// ```
+    pub fn parse_capitalized(s: &str) -> Result<ClassLikeTypeName, SemanticConversionError> {
+        if s.contains(tokens::SLASH) {
+            return ClassLikeTypeName::from_str(s);
+        }
+
+        let parts: Vec<&str> = s.split(tokens::DOT).collect();
+        let Some(class_start) = parts.iter()
+            .position(|part| part.starts_with(|ch: char| ch.is_uppercase())) else {
+            return ClassLikeTypeName::from_str(s);
+        };
+
+        let package = Package::from(
+            parts[..class_start].iter()
+                .map(|part| Name::from_str(part))
+                .collect::<Result<Vec<_>, SemanticConversionError>>()?
+        );
+
+        Ok(ClassLikeTypeName::nested(package, Self::parse_names(&parts[class_start..].join(tokens::DOT))?))
+    }
+
+    fn parse_names(s: &str) -> Result<Vec<Name>, SemanticConversionError> {
+        s.split(tokens::DOT)
+            .map(Name::from_str)
+            .collect()
     }
 }
 
@@ -138,4 +268,138 @@ mod tests {
         let class_like_type = ClassLikeTypeName::from_str("Class").unwrap();
         assert_eq!(class_like_type.render_string(), "Class");
     }
+
+    #[test]
+    fn test_from_string_metadata_nested() {
+        let class_like_type = ClassLikeTypeName::from_str("io/github/lexadiky/Outer.Inner").unwrap();
+        assert_eq!(
+            class_like_type,
+            ClassLikeTypeName::nested(
+                Package::from_str("io.github.lexadiky").unwrap(),
+                vec![Name::from("Outer"), Name::from("Inner")],
+            )
+        );
+        assert_eq!(class_like_type.render_string(), "io.github.lexadiky.Outer.Inner");
+    }
+
+    #[test]
+    fn test_from_string_metadata_invalid() {
+        assert!(ClassLikeTypeName::from_str("io/github/").is_err());
+        assert!(ClassLikeTypeName::from_str("io/github/Outer..Inner").is_err());
+    }
+
+    #[test]
+    fn test_from_string_metadata_empty_segment() {
+        let root_slash = ClassLikeTypeName::from_str("/Outer.Inner").unwrap_err();
+        assert_eq!(root_slash.position(), Some(0));
+
+        let double_slash = ClassLikeTypeName::from_str("a//b/X").unwrap_err();
+        assert_eq!(double_slash.position(), Some(2));
+    }
+
+    #[test]
+    fn test_from_string_metadata_dotted_package() {
+        let error = ClassLikeTypeName::from_str("a.b/Outer").unwrap_err();
+        assert_eq!(error.message(), "`a.b` contains tokens not allowed in kotlin identifier names");
+    }
+
+    #[test]
+    fn test_from_string_empty() {
+        assert!(ClassLikeTypeName::from_str("").is_err());
+    }
+
+    #[test]
+    fn test_parse_capitalized() {
+        let class_like_type = ClassLikeTypeName::parse_capitalized("io.github.lexadiky.Outer.Inner").unwrap();
+        assert_eq!(
+            class_like_type,
+            ClassLikeTypeName::nested(
+                Package::from_str("io.github.lexadiky").unwrap(),
+                vec![Name::from("Outer"), Name::from("Inner")],
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_capitalized_fallback() {
+        let class_like_type = ClassLikeTypeName::parse_capitalized("io.github.lexadiky.outer").unwrap();
+        assert_eq!(class_like_type, ClassLikeTypeName::from_str("io.github.lexadiky.outer").unwrap());
+    }
+
+    #[test]
+    fn test_simple_name() {
+        let class_like_type = ClassLikeTypeName::from_str("a/b/Outer.Inner").unwrap();
+        assert_eq!(class_like_type.simple_name(), &Name::from("Inner"));
+        assert_eq!(class_like_type.simple_names(), &[Name::from("Outer"), Name::from("Inner")]);
+        assert_eq!(class_like_type.package(), &Package::from_str("a.b").unwrap());
+    }
+
+    #[test]
+    fn test_enclosing_class() {
+        let class_like_type = ClassLikeTypeName::from_str("a/b/Outer.Inner").unwrap();
+        let enclosing = class_like_type.enclosing_class().unwrap();
+        assert_eq!(enclosing, ClassLikeTypeName::from_str("a.b.Outer").unwrap());
+        assert_eq!(enclosing.enclosing_class(), None);
+    }
+
+    #[test]
+    fn test_nested_class() {
+        let class_like_type = ClassLikeTypeName::from_str("a.b.Outer").unwrap();
+        assert_eq!(
+            class_like_type.nested_class("Builder"),
+            ClassLikeTypeName::from_str("a/b/Outer.Builder").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_peer_class() {
+        let top_level = ClassLikeTypeName::from_str("a.b.Outer").unwrap();
+        assert_eq!(
+            top_level.peer_class("OuterSerializer"),
+            ClassLikeTypeName::from_str("a.b.OuterSerializer").unwrap()
+        );
+
+        let nested = ClassLikeTypeName::from_str("a/b/Outer.Inner").unwrap();
+        assert_eq!(
+            nested.peer_class("Other"),
+            ClassLikeTypeName::from_str("a/b/Outer.Other").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_canonical_name_and_display() {
+        let class_like_type = ClassLikeTypeName::from_str("a/b/Outer.Inner").unwrap();
+        assert_eq!(class_like_type.canonical_name(), "a.b.Outer.Inner");
+        assert_eq!(class_like_type.to_string(), "a.b.Outer.Inner");
+    }
+
+    #[test]
+    fn test_ordering_is_consistent_with_equality() {
+        let top_level = ClassLikeTypeName::from_str("a.b.Outer").unwrap();
+        let nested = ClassLikeTypeName::from_str("a/Outer.b").unwrap();
+        let metadata_top_level = ClassLikeTypeName::from_str("a/b/Outer").unwrap();
+
+        assert_eq!(top_level.cmp(&metadata_top_level), std::cmp::Ordering::Equal);
+        assert_ne!(ClassLikeTypeName::from_str("a.Outer.b").unwrap().cmp(&nested), std::cmp::Ordering::Equal);
+        assert!(nested < top_level);
+    }
+
+    #[test]
+    fn test_hash_set_deduplication() {
+        let set: std::collections::HashSet<ClassLikeTypeName> = [
+            ClassLikeTypeName::from_str("a.b.Outer").unwrap(),
+            ClassLikeTypeName::from_str("a/b/Outer").unwrap(),
+            ClassLikeTypeName::from_str("a.b.Other").unwrap(),
+        ].into_iter().collect();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let class_like_type = ClassLikeTypeName::from_str("a/b/Outer.Inner").unwrap();
+        let reparsed = ClassLikeTypeName::parse_capitalized(
+            class_like_type.render_string().as_str()
+        ).unwrap();
+        assert_eq!(class_like_type, reparsed);
+    }
 }
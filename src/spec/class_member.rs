@@ -0,0 +1,65 @@
+use std::cmp::Ordering;
+
+use crate::spec::{Class, ClassMemberKind, CodeBlock, Function, Name, Property, SecondaryConstructor, Type, TypeAlias};
+
+/// Borrowed view of a member of class body, used by [crate::spec::MemberOrder::Custom] comparators
+#[derive(Debug, Clone, Copy)]
+pub enum ClassMember<'a> {
+    Property(&'a Property),
+    InitBlock(&'a CodeBlock),
+    SecondaryConstructor(&'a SecondaryConstructor),
+    Function(&'a Function),
+    Subclass(&'a Class),
+    TypeAlias(&'a TypeAlias),
+}
+
+impl ClassMember<'_> {
+    /// Name of the member, [None] for init blocks and secondary constructors
+    pub fn name(&self) -> Option<&Name> {
+        match self {
+            ClassMember::Property(property) => Some(property.name()),
+            ClassMember::Function(function) => Some(function.name()),
+            ClassMember::Subclass(subclass) => Some(subclass.name()),
+            ClassMember::TypeAlias(type_alias) => Some(type_alias.name()),
+            ClassMember::InitBlock(_) | ClassMember::SecondaryConstructor(_) => None,
+        }
+    }
+
+    /// Kind of the member
+    pub fn kind(&self) -> ClassMemberKind {
+        match self {
+            ClassMember::Property(_) => ClassMemberKind::Property,
+            ClassMember::InitBlock(_) => ClassMemberKind::InitBlock,
+            ClassMember::SecondaryConstructor(_) => ClassMemberKind::SecondaryConstructor,
+            ClassMember::Function(_) => ClassMemberKind::Function,
+            ClassMember::Subclass(_) => ClassMemberKind::Subclass,
+            ClassMember::TypeAlias(_) => ClassMemberKind::TypeAlias,
+        }
+    }
+
+    /// Parameter types of functions and secondary constructors, useful to order overloads.
+    /// Empty for other members.
+    pub fn signature(&self) -> Vec<&Type> {
+        match self {
+            ClassMember::Function(function) => function.parameter_types().collect(),
+            ClassMember::SecondaryConstructor(constructor) => constructor.parameter_types().collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Compares members by [Kotlin coding conventions](https://kotlinlang.org/docs/coding-conventions.html#class-layout):
+    /// property declarations and init blocks, secondary constructors, functions, then nested classes and type aliases.
+    /// Members of the same group are considered equal, so stable sort keeps their relative order.
+    pub fn compare_by_conventions(&self, other: &ClassMember) -> Ordering {
+        self.convention_rank().cmp(&other.convention_rank())
+    }
+
+    fn convention_rank(&self) -> u8 {
+        match self {
+            ClassMember::Property(_) | ClassMember::InitBlock(_) => 0,
+            ClassMember::SecondaryConstructor(_) => 1,
+            ClassMember::Function(_) => 2,
+            ClassMember::Subclass(_) | ClassMember::TypeAlias(_) => 3,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+/// Kind of [crate::spec::ClassMember], see [crate::spec::ClassMember::kind].
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+pub enum ClassMemberKind {
+    Property,
+    InitBlock,
+    SecondaryConstructor,
+    Function,
+    Subclass,
+    TypeAlias,
+}
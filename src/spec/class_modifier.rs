@@ -0,0 +1,81 @@
+use crate::io::RenderKotlin;
+use crate::spec::CodeBlock;
+use crate::tokens;
+
+/// Modifier of class-like declaration, multiple modifiers could be combined, see [crate::spec::Class::modifier].
+/// Declaration order of variants is the order modifiers are rendered in,
+/// it follows [canonical modifiers order](https://kotlinlang.org/docs/coding-conventions.html#modifiers-order).
+///
+/// Modality modifiers ([ClassModifier::Open], [ClassModifier::Abstract], [ClassModifier::Sealed])
+/// are mutually exclusive as well as [ClassModifier::Expect] and [ClassModifier::Actual].
+/// If class has no modality modifier, `final` is rendered, unless it is data or value class.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone)]
+pub enum ClassModifier {
+    /// `expect` declaration of multiplatform project
+    Expect,
+    /// `actual` declaration of multiplatform project
+    Actual,
+    /// Class that can be inherited
+    Open,
+    /// Abstract class
+    Abstract,
+    /// Sealed class or interface
+    Sealed,
+    /// Inner class, holding reference to outer class instance
+    Inner,
+    /// Functional interface `fun interface`
+    Fun,
+    /// [Value class](https://kotlinlang.org/docs/inline-classes.html), `@JvmInline` annotation is added automatically
+    Value,
+    /// Data class or data object
+    Data,
+}
+
+impl ClassModifier {
+    /// Checks if modifier is one of `open`, `abstract` or `sealed`
+    pub(crate) fn is_modality(&self) -> bool {
+        matches!(self, ClassModifier::Open | ClassModifier::Abstract | ClassModifier::Sealed)
+    }
+
+    /// Checks if modifier is one of `expect` or `actual`
+    pub(crate) fn is_multiplatform(&self) -> bool {
+        matches!(self, ClassModifier::Expect | ClassModifier::Actual)
+    }
+}
+
+impl RenderKotlin for ClassModifier {
+    fn render_into(&self, block: &mut CodeBlock) {
+        let keyword = match self {
+            ClassModifier::Expect => tokens::keyword::EXPECT,
+            ClassModifier::Actual => tokens::keyword::ACTUAL,
+            ClassModifier::Inner => tokens::keyword::INNER,
+            ClassModifier::Open => tokens::keyword::OPEN,
+            ClassModifier::Abstract => tokens::keyword::ABSTRACT,
+            ClassModifier::Sealed => tokens::keyword::SEALED,
+            ClassModifier::Fun => tokens::keyword::FUN,
+            ClassModifier::Data => tokens::keyword::DATA,
+            ClassModifier::Value => tokens::keyword::VALUE,
+        };
+        block.push_static_atom(keyword);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::RenderKotlin;
+    use crate::spec::ClassModifier;
+
+    #[test]
+    fn test_render() {
+        assert_eq!(ClassModifier::Expect.render_string(), "expect");
+        assert_eq!(ClassModifier::Fun.render_string(), "fun");
+        assert_eq!(ClassModifier::Value.render_string(), "value");
+    }
+
+    #[test]
+    fn test_canonical_order() {
+        assert!(ClassModifier::Actual < ClassModifier::Abstract);
+        assert!(ClassModifier::Sealed < ClassModifier::Inner);
+        assert!(ClassModifier::Inner < ClassModifier::Data);
+    }
+}
@@ -6,7 +6,7 @@ use crate::util::{SemanticConversionError, yolo_from_str};
 
 /// Node of a code block that can be rendered to a Kotlin code.
 /// You can treat these nodes as commands for rendering, like "add atom", "add new line", etc.
-#[derive(Debug, Clone)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone)]
 pub(crate) enum CodeBlockNode {
     Atom(String),
     StaticAtom(&'static str),
@@ -14,10 +14,14 @@ pub(crate) enum CodeBlockNode {
     NewLine,
     Indent(usize),
     Unindent(usize),
+    /// Resets indent to the one of the current line start, remembering the previous value
+    AlignIndent,
+    /// Restores indent remembered by the matching [CodeBlockNode::AlignIndent]
+    RestoreIndent,
 }
 
 /// Plain list of nodes that can be rendered to a Kotlin code.
-#[derive(Debug, Clone)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone)]
 pub struct CodeBlock {
     pub(crate) nodes: Vec<CodeBlockNode>,
 }
@@ -130,6 +134,17 @@ impl CodeBlock {
         self.push_static_atom(tokens::CURLY_BRACKET_RIGHT);
     }
 
+    /// Renders [block] with indent of the line it starts on, ignoring indents pushed in the middle of that line,
+    /// e.g. continuation indent of property initializer.
+    pub(crate) fn push_line_aligned<F>(&mut self, block: F)
+    where
+        F: FnOnce(&mut CodeBlock),
+    {
+        self.nodes.push(CodeBlockNode::AlignIndent);
+        block(self);
+        self.nodes.push(CodeBlockNode::RestoreIndent);
+    }
+
     /// Surrounds first parameter [block] with round brackets and adds it to [self].
     pub fn push_round_brackets<F>(&mut self, block: F)
     where
@@ -174,26 +189,33 @@ impl CodeBlock {
         self.push_renderable(&code);
     }
 
-    fn push_indent_into(indent: usize, root_buffer: &mut CodeBuffer) {
-        if matches!(root_buffer.last_char(), Some(tokens::NEW_LINE_CH)) {
-            for _ in 0..indent {
-                root_buffer.push(tokens::INDENT)
+    fn push_indent_into(indent: usize, line_indent: &mut usize, root_buffer: &mut CodeBuffer) {
+        match root_buffer.last_char() {
+            Some(tokens::NEW_LINE_CH) => {
+                for _ in 0..indent {
+                    root_buffer.push(tokens::INDENT)
+                }
+                *line_indent = indent;
             }
+            None => *line_indent = indent,
+            _ => {}
         }
     }
 
     fn render(&self) -> String {
         let mut root_buffer = CodeBuffer::default();
         let mut indent = 0;
+        let mut line_indent = 0;
+        let mut aligned_indents = Vec::new();
 
         for node in &self.nodes {
             match node {
                 CodeBlockNode::Atom(buffer) => {
-                    Self::push_indent_into(indent, &mut root_buffer);
+                    Self::push_indent_into(indent, &mut line_indent, &mut root_buffer);
                     root_buffer.push(buffer.as_str());
                 }
                 CodeBlockNode::StaticAtom(buffer) => {
-                    Self::push_indent_into(indent, &mut root_buffer);
+                    Self::push_indent_into(indent, &mut line_indent, &mut root_buffer);
                     root_buffer.push(buffer);
                 }
                 CodeBlockNode::Indent(size) => {
@@ -206,6 +228,13 @@ impl CodeBlock {
                     }
                     indent -= size;
                 }
+                CodeBlockNode::AlignIndent => {
+                    aligned_indents.push(indent);
+                    indent = line_indent;
+                }
+                CodeBlockNode::RestoreIndent => {
+                    indent = aligned_indents.pop().unwrap_or(indent);
+                }
                 CodeBlockNode::Space => {
                     root_buffer.push(tokens::SPACE)
                 }
@@ -1,47 +1,77 @@
 use crate::io::RenderKotlin;
-use crate::spec::{VisibilityModifier, Class, CodeBlock, Function, Property};
+use crate::spec::{VisibilityModifier, Annotation, Class, CodeBlock, Function, MemberOrder, Name, Property, Supertype, TypeAlias};
+use crate::spec::annotation::{mixin_annotation_mutators, AnnotationSlot};
+use crate::spec::class::ClassMemberNode;
 use crate::spec::kdoc::{KdocSlot, mixin_kdoc_mutators};
 use crate::tokens;
+use crate::util::ValidationError;
 
 /// Companion object for class
-/// Can contain properties, functions, subclasses and init blocks like class itself.
+/// Can contain properties, functions, subclasses, nested objects, type aliases and init blocks like class itself.
 #[derive(Debug, Clone)]
 pub struct CompanionObject {
+    name: Option<Name>,
     visibility_modifier: VisibilityModifier,
-    member_nodes: Vec<crate::spec::class::ClassMemberNode>,
+    supertypes: Vec<Supertype>,
+    member_nodes: Vec<ClassMemberNode>,
+    member_order: Option<MemberOrder>,
+    annotation_slot: AnnotationSlot,
     kdoc: KdocSlot
 }
 
 impl CompanionObject {
     pub fn new() -> Self {
         CompanionObject {
+            name: None,
             member_nodes: Vec::new(),
+            member_order: None,
+            supertypes: Vec::new(),
             visibility_modifier: VisibilityModifier::default(),
+            annotation_slot: AnnotationSlot::vertical(),
             kdoc: KdocSlot::default()
         }
     }
 
+    /// Sets name of the companion object, unnamed companion objects are called `Companion` in Kotlin
+    pub fn name<NameLike: Into<Name>>(mut self, name: NameLike) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Adds supertype to the companion object, e.g. `companion object: JsonAdapter.Factory`
+    pub fn inherits<SupertypeLike: Into<Supertype>>(mut self, supertype: SupertypeLike) -> Self {
+        self.supertypes.push(supertype.into());
+        self
+    }
+
     /// Adds a property to the companion object
     pub fn property(mut self, property: Property) -> Self {
-        self.member_nodes.push(crate::spec::class::ClassMemberNode::Property(property));
+        self.member_nodes.push(ClassMemberNode::Property(property));
         self
     }
 
     /// Adds a function to the companion object
     pub fn function(mut self, function: Function) -> Self {
-        self.member_nodes.push(crate::spec::class::ClassMemberNode::Function(function));
+        self.member_nodes.push(ClassMemberNode::Function(function));
         self
     }
 
-    /// Adds a subclass to the companion object
+    /// Adds a subclass to the companion object.
+    /// Nested objects are added the same way, as [Class] with [crate::spec::ClassKind::Object].
     pub fn subclass(mut self, subclass: Class) -> Self {
-        self.member_nodes.push(crate::spec::class::ClassMemberNode::Subclass(subclass));
+        self.member_nodes.push(ClassMemberNode::Subclass(subclass));
+        self
+    }
+
+    /// Adds a type alias to the companion object
+    pub fn type_alias(mut self, type_alias: TypeAlias) -> Self {
+        self.member_nodes.push(ClassMemberNode::TypeAlias(type_alias));
         self
     }
 
     /// Adds an init block to the companion object
     pub fn init<CodeBlockLike: Into<CodeBlock>>(mut self, block: CodeBlockLike) -> Self {
-        self.member_nodes.push(crate::spec::class::ClassMemberNode::InitBlock(block.into()));
+        self.member_nodes.push(ClassMemberNode::InitBlock(block.into()));
         self
     }
 
@@ -51,31 +81,92 @@ impl CompanionObject {
         self
     }
 
+    /// Sets [MemberOrder] of companion object body.
+    /// By default, order of the enclosing class is used, see [crate::spec::Class::member_order].
+    pub fn member_order(mut self, member_order: MemberOrder) -> Self {
+        self.member_order = Some(member_order);
+        self
+    }
+
+    /// Checks that members of the companion object are consistent, see [crate::spec::Class::validate]
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        ClassMemberNode::validate_all(&self.member_nodes, false, "companion object")
+    }
+
+    mixin_annotation_mutators!();
     mixin_kdoc_mutators!();
-}
 
-impl RenderKotlin for CompanionObject {
-    fn render_into(&self, block: &mut CodeBlock) {
+    /// Renders companion object, using [class_member_order] unless it has own [MemberOrder]
+    pub(crate) fn render_ordered_into(&self, block: &mut CodeBlock, class_member_order: &MemberOrder) {
+        let member_order = self.member_order.as_ref().unwrap_or(class_member_order);
         block.push_renderable(&self.kdoc);
+        block.push_renderable(&self.annotation_slot);
         block.push_renderable(&self.visibility_modifier);
         block.push_space();
         block.push_static_atom(tokens::keyword::COMPANION);
         block.push_space();
         block.push_static_atom(tokens::keyword::OBJECT);
         block.push_space();
+        if let Some(name) = &self.name {
+            block.push_renderable(name);
+            block.push_space();
+        }
+        if !self.supertypes.is_empty() {
+            block.pop_space();
+            block.push_static_atom(tokens::COLON);
+            block.push_space();
+            block.push_comma_separated(&self.supertypes);
+            block.push_space();
+        }
         block.push_curly_brackets(|code| {
-            for node in &self.member_nodes {
-                code.push_renderable(node);
+            let mut members = self.member_nodes.iter()
+                .map(ClassMemberNode::as_member)
+                .collect::<Vec<_>>();
+            member_order.sort(&mut members);
+            for member in &members {
+                code.push_renderable(member);
                 code.push_new_line();
             }
         });
     }
 }
 
+impl RenderKotlin for CompanionObject {
+    fn render_into(&self, block: &mut CodeBlock) {
+        self.render_ordered_into(block, &MemberOrder::default());
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::io::RenderKotlin;
-    use crate::spec::{Class, CodeBlock, CompanionObject, Function, Parameter, Name, Property, Type};
+    use crate::spec::{Annotation, Class, ClassKind, ClassLikeTypeName, CodeBlock, CompanionObject, Function, Parameter, Name, Property, Type, TypeAlias};
+
+    #[test]
+    fn named_companion_object_with_supertypes() {
+        let companion = CompanionObject::new()
+            .name("Factory")
+            .inherits(ClassLikeTypeName::from("a.JsonAdapter.Factory"))
+            .annotation(Annotation::new(ClassLikeTypeName::from("a.Generated")))
+            .kdoc("Creates adapters");
+
+        assert_eq!(
+            companion.render_string(),
+            "/**\n * Creates adapters\n */\n@a.Generated()\npublic companion object Factory: a.JsonAdapter.Factory {\n}"
+        );
+    }
+
+    #[test]
+    fn companion_object_with_nested_object_and_type_alias() {
+        let companion = CompanionObject::new()
+            .type_alias(TypeAlias::new("Id", Type::string()))
+            .subclass(Class::new("Default").kind(ClassKind::Object));
+
+        assert_eq!(
+            companion.render_string(),
+            "public companion object {\n    public typealias Id = kotlin.String\n    public object Default {\n\n    }\n}"
+        );
+    }
 
     #[test]
     fn companion_object() {
@@ -0,0 +1,136 @@
+use crate::io::RenderKotlin;
+use crate::spec::{CodeBlock, Name, Type};
+use crate::tokens;
+use crate::util::ValidationError;
+
+/// Entry of `context(...)` clause of a [crate::spec::Function] or a [crate::spec::Property].
+///
+/// Both older [context receivers](https://kotlinlang.org/docs/whatsnew1620.html#prototype-of-context-receivers-for-kotlin-jvm)
+/// syntax `context(Logger)` and newer named context parameters syntax `context(logger: Logger)` are supported.
+/// Kotlin doesn't allow to mix them in one declaration, see [crate::spec::Function::validate] and [crate::spec::Property::validate].
+///
+/// # Examples
+/// ```rust
+/// use kotlin_poet_rs::io::RenderKotlin;
+/// use kotlin_poet_rs::spec::{ClassLikeTypeName, ContextParameter};
+///
+/// let logger = ClassLikeTypeName::from("a.Logger");
+///
+/// assert_eq!(ContextParameter::receiver(logger.clone()).render_string(), "a.Logger");
+/// assert_eq!(ContextParameter::named("logger", logger).render_string(), "logger: a.Logger");
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone)]
+pub enum ContextParameter {
+    /// Unnamed context receiver, e.g. `Logger`
+    Receiver(Type),
+    /// Named context parameter, e.g. `logger: Logger`
+    Named {
+        name: Name,
+        parameter_type: Type,
+    },
+}
+
+impl ContextParameter {
+    /// Creates unnamed context receiver
+    pub fn receiver<TypeLike: Into<Type>>(receiver_type: TypeLike) -> ContextParameter {
+        ContextParameter::Receiver(receiver_type.into())
+    }
+
+    /// Creates named context parameter
+    pub fn named<NameLike: Into<Name>, TypeLike: Into<Type>>(
+        name: NameLike,
+        parameter_type: TypeLike,
+    ) -> ContextParameter {
+        ContextParameter::Named {
+            name: name.into(),
+            parameter_type: parameter_type.into(),
+        }
+    }
+
+    /// Type of the context receiver or context parameter
+    pub fn parameter_type(&self) -> &Type {
+        match self {
+            ContextParameter::Receiver(receiver_type) => receiver_type,
+            ContextParameter::Named { parameter_type, .. } => parameter_type,
+        }
+    }
+
+    /// Replaces type of the context parameter with the result of [transform]
+    pub(crate) fn map_type<F: FnMut(Type) -> Type>(self, transform: &mut F) -> ContextParameter {
+        match self {
+            ContextParameter::Receiver(receiver_type) => ContextParameter::Receiver(transform(receiver_type)),
+            ContextParameter::Named { name, parameter_type } => ContextParameter::Named {
+                name,
+                parameter_type: transform(parameter_type),
+            },
+        }
+    }
+
+    /// Checks that context receivers and named context parameters are not mixed in one clause
+    pub(crate) fn validate_clause(context_parameters: &[ContextParameter]) -> Result<(), ValidationError> {
+        let has_receivers = context_parameters.iter().any(|it| matches!(it, ContextParameter::Receiver(_)));
+        let has_named = context_parameters.iter().any(|it| matches!(it, ContextParameter::Named { .. }));
+        if has_receivers && has_named {
+            return Err(ValidationError::new(
+                "Context receivers and named context parameters can't be mixed in one declaration"
+            ));
+        }
+        Ok(())
+    }
+
+    /// Renders `context(...)` clause, produces empty [CodeBlock] if there are no context parameters
+    pub(crate) fn render_clause(context_parameters: &[ContextParameter]) -> CodeBlock {
+        let mut block = CodeBlock::empty();
+        if context_parameters.is_empty() {
+            return block;
+        }
+
+        block.push_static_atom(tokens::keyword::CONTEXT);
+        block.push_round_brackets(|code| {
+            code.push_comma_separated(context_parameters);
+        });
+        block
+    }
+}
+
+impl RenderKotlin for ContextParameter {
+    fn render_into(&self, block: &mut CodeBlock) {
+        match self {
+            ContextParameter::Receiver(receiver_type) => block.push_renderable(receiver_type),
+            ContextParameter::Named { name, parameter_type } => {
+                block.push_renderable(name);
+                block.push_static_atom(tokens::COLON);
+                block.push_space();
+                block.push_renderable(parameter_type);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::RenderKotlin;
+    use crate::spec::{ContextParameter, Type};
+
+    #[test]
+    fn render_clause() {
+        let clause = ContextParameter::render_clause(&[
+            ContextParameter::receiver(Type::string()),
+            ContextParameter::receiver(Type::int()),
+        ]);
+        assert_eq!(clause.render_string(), "context(kotlin.String, kotlin.Int)");
+    }
+
+    #[test]
+    fn render_named_clause() {
+        let clause = ContextParameter::render_clause(&[
+            ContextParameter::named("text", Type::string()),
+        ]);
+        assert_eq!(clause.render_string(), "context(text: kotlin.String)");
+    }
+
+    #[test]
+    fn render_empty_clause() {
+        assert_eq!(ContextParameter::render_clause(&[]).render_string(), "");
+    }
+}
@@ -0,0 +1,19 @@
+use crate::spec::Name;
+
+/// Definitely non-nullable version of generic type (e.g. `T & kotlin.Any`).
+/// Can only be created via [crate::spec::Type::definitely_non_nullable].
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone)]
+pub struct DefinitelyNonNullableType {
+    generic: Name,
+}
+
+impl DefinitelyNonNullableType {
+    pub(crate) fn new(generic: Name) -> DefinitelyNonNullableType {
+        DefinitelyNonNullableType { generic }
+    }
+
+    /// Name of the generic parameter
+    pub fn generic(&self) -> &Name {
+        &self.generic
+    }
+}
@@ -0,0 +1,128 @@
+use crate::io::RenderKotlin;
+use crate::spec::{Annotation, Argument, CodeBlock, Function, Name, Property};
+use crate::spec::annotation::{mixin_annotation_mutators, AnnotationSlot};
+use crate::spec::class::ClassMemberNode;
+use crate::spec::kdoc::{KdocSlot, mixin_kdoc_mutators};
+
+/// Entry of enum class, see [crate::spec::Class::enum_entry].
+/// Could have constructor arguments and a body overriding members of the enum class.
+///
+/// [Official documentation reference](https://kotlinlang.org/docs/enum-classes.html)
+///
+/// # Examples
+/// ```rust
+/// use kotlin_poet_rs::io::RenderKotlin;
+/// use kotlin_poet_rs::spec::{Argument, CodeBlock, EnumEntry, Function, Type};
+///
+/// let entry = EnumEntry::new("Red")
+///     .argument(Argument::new_positional(CodeBlock::atom("0xFF0000")))
+///     .function(
+///         Function::new("describe")
+///             .overrides(true)
+///             .returns(Type::string())
+///             .body(CodeBlock::statement("return \"red\""))
+///     );
+///
+/// assert_eq!(
+///     entry.render_string(),
+///     "Red(0xFF0000) {\n    public override fun describe(): kotlin.String {\n        return \"red\"\n    }\n}"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct EnumEntry {
+    name: Name,
+    arguments: Vec<Argument>,
+    member_nodes: Vec<ClassMemberNode>,
+    annotation_slot: AnnotationSlot,
+    kdoc: KdocSlot,
+}
+
+impl EnumEntry {
+    /// Creates enum entry without arguments and body
+    pub fn new<NameLike: Into<Name>>(name: NameLike) -> EnumEntry {
+        EnumEntry {
+            name: name.into(),
+            arguments: Vec::new(),
+            member_nodes: Vec::new(),
+            annotation_slot: AnnotationSlot::vertical(),
+            kdoc: KdocSlot::default(),
+        }
+    }
+
+    /// Adds enum class constructor argument. Arguments will appear in order this method is called.
+    pub fn argument(mut self, argument: Argument) -> EnumEntry {
+        self.arguments.push(argument);
+        self
+    }
+
+    /// Adds property to entry body. Members will appear in order they are added.
+    pub fn property(mut self, property: Property) -> EnumEntry {
+        self.member_nodes.push(ClassMemberNode::Property(property));
+        self
+    }
+
+    /// Adds function to entry body. Members will appear in order they are added.
+    pub fn function(mut self, function: Function) -> EnumEntry {
+        self.member_nodes.push(ClassMemberNode::Function(function));
+        self
+    }
+
+    mixin_annotation_mutators!();
+    mixin_kdoc_mutators!();
+}
+
+impl RenderKotlin for EnumEntry {
+    fn render_into(&self, block: &mut CodeBlock) {
+        block.push_renderable(&self.kdoc);
+        block.push_renderable(&self.annotation_slot);
+        block.push_renderable(&self.name);
+
+        if !self.arguments.is_empty() {
+            block.push_round_brackets(|arguments_code| {
+                arguments_code.push_comma_separated(&self.arguments);
+            });
+        }
+
+        if !self.member_nodes.is_empty() {
+            block.push_space();
+            block.push_curly_brackets(|body_code| {
+                for node in &self.member_nodes {
+                    body_code.push_renderable(node);
+                    body_code.push_new_line();
+                }
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::RenderKotlin;
+    use crate::spec::{Annotation, Argument, ClassLikeTypeName, CodeBlock, EnumEntry, KDoc};
+
+    #[test]
+    fn render_without_arguments() {
+        assert_eq!(EnumEntry::new("Red").render_string(), "Red");
+    }
+
+    #[test]
+    fn render_with_arguments() {
+        let entry = EnumEntry::new("Red")
+            .argument(Argument::new_positional(CodeBlock::atom("255")))
+            .argument(Argument::new_named("alpha", CodeBlock::atom("0")));
+
+        assert_eq!(entry.render_string(), "Red(255, alpha = 0)");
+    }
+
+    #[test]
+    fn render_with_annotation_and_kdoc() {
+        let entry = EnumEntry::new("Red")
+            .kdoc(KDoc::from("Primary color"))
+            .annotation(Annotation::new(ClassLikeTypeName::from("kotlin.Deprecated")));
+
+        assert_eq!(
+            entry.render_string(),
+            "/**\n * Primary color\n */\n@kotlin.Deprecated()\nRed"
+        );
+    }
+}
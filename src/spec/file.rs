@@ -1,6 +1,7 @@
 use crate::io::RenderKotlin;
 use crate::spec::{Annotation, AnnotationTarget, Class, ClassLikeTypeName, CodeBlock, Comment, Function, Import, Package, Property, TypeAlias};
 use crate::tokens;
+use crate::util::ValidationError;
 
 #[derive(Debug, Clone)]
 enum KotlinFileNode {
@@ -93,6 +94,30 @@ impl KotlinFile {
     }
 }
 
+impl KotlinFile {
+    /// Validates all declarations of the file, see [Class::validate] and [Function::validate]
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        for node in &self.nodes {
+            match node {
+                KotlinFileNode::Property(property) => property.validate()?,
+                KotlinFileNode::Function(function) => {
+                    function.validate()?;
+                    if function.is_abstract() {
+                        return Err(ValidationError::new(&format!(
+                            "Top level function `{}` can't be abstract",
+                            function.name().render_string()
+                        )));
+                    }
+                }
+                KotlinFileNode::Class(class) => class.validate()?,
+                KotlinFileNode::TypeAlias(_) => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl From<ClassLikeTypeName> for KotlinFile {
     fn from(value: ClassLikeTypeName) -> Self {
         let package = value.package;
@@ -160,7 +185,28 @@ impl RenderKotlin for KotlinFile {
 #[cfg(test)]
 mod tests {
     use crate::io::RenderKotlin;
-    use crate::spec::{Comment, Function, KotlinFile};
+    use crate::spec::{Class, CodeBlock, Comment, Function, KotlinFile, MemberInheritanceModifier};
+
+    #[test]
+    fn test_validate() {
+        let main = Function::new("main").body(CodeBlock::statement("println()"));
+        assert!(KotlinFile::root().function(main.clone()).validate().is_ok());
+        assert!(
+            KotlinFile::root()
+                .function(main.clone().inheritance_modifier(MemberInheritanceModifier::Abstract))
+                .validate().is_err()
+        );
+        assert!(
+            KotlinFile::root()
+                .function(main.clone().external(true))
+                .validate().is_err()
+        );
+        assert!(
+            KotlinFile::root()
+                .class(Class::new("Runner").function(main.inheritance_modifier(MemberInheritanceModifier::Abstract)))
+                .validate().is_err()
+        );
+    }
 
     #[test]
     fn test_root_file() {
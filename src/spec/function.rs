@@ -1,22 +1,31 @@
 use crate::io::RenderKotlin;
-use crate::spec::{VisibilityModifier, CodeBlock, GenericParameter, MemberInheritanceModifier, Name, Type, Parameter, Annotation};
+use crate::spec::{VisibilityModifier, CodeBlock, ContextParameter, GenericParameter, MemberInheritanceModifier, Name, Type, Parameter, Annotation};
+use crate::spec::function_body::FunctionBody;
 use crate::spec::annotation::{mixin_annotation_mutators, AnnotationSlot};
 use crate::spec::kdoc::{KdocSlot, mixin_kdoc_mutators};
 use crate::tokens;
+use crate::util::ValidationError;
 
 #[derive(Debug, Clone)]
 pub struct Function {
     name: Name,
     visibility_modifier: VisibilityModifier,
     parameters: Vec<Parameter>,
-    body: Option<CodeBlock>,
+    body: Option<FunctionBody>,
     returns: Type,
+    is_return_type_omitted: bool,
     receiver: Option<Type>,
+    context_parameters: Vec<ContextParameter>,
     inheritance_modifier: MemberInheritanceModifier,
     is_suspended: bool,
     is_inline: bool,
     is_operator: bool,
     is_override: bool,
+    is_infix: bool,
+    is_tailrec: bool,
+    is_external: bool,
+    is_expect: bool,
+    is_actual: bool,
     generic_parameters: Vec<GenericParameter>,
     annotation_slot: AnnotationSlot,
     kdoc: KdocSlot,
@@ -30,13 +39,20 @@ impl Function {
             parameters: Vec::new(),
             body: None,
             returns: Type::unit(),
+            is_return_type_omitted: false,
             receiver: None,
+            context_parameters: Vec::new(),
             inheritance_modifier: MemberInheritanceModifier::Final,
             is_suspended: false,
             is_inline: false,
             is_operator: false,
             generic_parameters: Vec::new(),
             is_override: false,
+            is_infix: false,
+            is_tailrec: false,
+            is_external: false,
+            is_expect: false,
+            is_actual: false,
             annotation_slot: AnnotationSlot::vertical(),
             kdoc: KdocSlot::default(),
         }
@@ -58,7 +74,22 @@ impl Function {
     }
 
     pub fn body<CodeBlockLike: Into<CodeBlock>>(mut self, body: CodeBlockLike) -> Function {
-        self.body = Some(body.into());
+        self.body = Some(FunctionBody::Block(body.into()));
+        self
+    }
+
+    /// Sets expression body, e.g. `fun id(): kotlin.String = value`.
+    /// Exclusive with [Function::body]
+    pub fn expression_body<CodeBlockLike: Into<CodeBlock>>(mut self, expression: CodeBlockLike) -> Function {
+        self.body = Some(FunctionBody::expression(expression));
+        self
+    }
+
+    /// Omits return type declaration where Kotlin allows it: for functions with expression body
+    /// (type is inferred) and for functions returning `kotlin.Unit`.
+    /// Return type is still rendered in other cases.
+    pub fn omit_return_type(mut self, flag: bool) -> Function {
+        self.is_return_type_omitted = flag;
         self
     }
 
@@ -72,6 +103,13 @@ impl Function {
         self
     }
 
+    /// Adds [ContextParameter] to the function's `context(...)` clause.
+    /// Multiple context parameters can be added, they will appear in order this method is called.
+    pub fn context_parameter(mut self, context_parameter: ContextParameter) -> Function {
+        self.context_parameters.push(context_parameter);
+        self
+    }
+
     pub fn inheritance_modifier(mut self, inheritance_modifier: MemberInheritanceModifier) -> Function {
         self.inheritance_modifier = inheritance_modifier;
         self
@@ -97,35 +135,146 @@ impl Function {
         self
     }
 
+    /// Marks function as `infix`, function must have exactly one parameter
+    pub fn infix(mut self, flag: bool) -> Function {
+        self.is_infix = flag;
+        self
+    }
+
+    /// Marks function as `tailrec`
+    pub fn tailrec(mut self, flag: bool) -> Function {
+        self.is_tailrec = flag;
+        self
+    }
+
+    /// Marks function as `external`, e.g. implemented via JNI. External functions have no body
+    pub fn external(mut self, flag: bool) -> Function {
+        self.is_external = flag;
+        self
+    }
+
+    /// Marks function as multiplatform `expect` declaration.
+    /// Exclusive with [Function::actual]
+    pub fn expect(mut self, flag: bool) -> Function {
+        self.is_expect = flag;
+        if flag {
+            self.is_actual = false;
+        }
+        self
+    }
+
+    /// Marks function as multiplatform `actual` declaration.
+    /// Exclusive with [Function::expect]
+    pub fn actual(mut self, flag: bool) -> Function {
+        self.is_actual = flag;
+        if flag {
+            self.is_expect = false;
+        }
+        self
+    }
+
+    /// Checks that modifiers, parameters and body of the function are consistent.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use kotlin_poet_rs::spec::{CodeBlock, Function, Parameter, Type};
+    ///
+    /// let plus = Function::new("plus")
+    ///     .infix(true)
+    ///     .parameter(Parameter::new("other", Type::int()))
+    ///     .expression_body(CodeBlock::atom("this + other"));
+    ///
+    /// assert!(plus.validate().is_ok());
+    /// assert!(Function::new("plus").infix(true).validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        ContextParameter::validate_clause(&self.context_parameters)?;
+
+        if self.body.is_some() {
+            let bodiless = [
+                (self.is_external, tokens::keyword::EXTERNAL),
+                (self.is_expect, tokens::keyword::EXPECT),
+                (self.is_abstract(), tokens::keyword::ABSTRACT),
+            ];
+            if let Some((_, keyword)) = bodiless.iter().find(|(flag, _)| *flag) {
+                return Err(ValidationError::new(&format!("`{}` function can't have a body", keyword)));
+            }
+        }
+
+        if self.is_infix {
+            let is_single_parameter = matches!(
+                self.parameters.as_slice(),
+                [parameter] if !parameter.is_vararg() && !parameter.has_default_value()
+            );
+            if !is_single_parameter {
+                return Err(ValidationError::new(
+                    "`infix` function must have exactly one parameter without default value or `vararg`"
+                ));
+            }
+        }
+
+        if self.is_tailrec && self.inheritance_modifier != MemberInheritanceModifier::Final {
+            return Err(ValidationError::new("`tailrec` function can't be `open` or `abstract`"));
+        }
+
+        if self.is_abstract() && (self.is_external || self.is_inline || self.is_tailrec) {
+            return Err(ValidationError::new(
+                "`abstract` function can't be `external`, `inline` or `tailrec`"
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Name of the declaration
+    pub(crate) fn name(&self) -> &Name {
+        &self.name
+    }
+
+    /// Types of parameters in order of declaration
+    pub(crate) fn parameter_types(&self) -> impl Iterator<Item = &Type> {
+        self.parameters.iter().map(Parameter::parameter_type)
+    }
+
+    /// Checks if function is declared as `abstract`
+    pub(crate) fn is_abstract(&self) -> bool {
+        self.inheritance_modifier == MemberInheritanceModifier::Abstract
+    }
+
     mixin_annotation_mutators!();
     mixin_kdoc_mutators!();
 }
 
 impl RenderKotlin for Function {
     fn render_into(&self, block: &mut CodeBlock) {
-        block.push_renderable(&self.kdoc);
+        block.push_renderable(&self.kdoc.with_parameter_tags(&self.parameters));
         block.push_renderable(&self.annotation_slot);
 
-        block.push_renderable(&self.visibility_modifier);
-        block.push_space();
-
-        if self.is_suspended {
-            block.push_static_atom(tokens::keyword::SUSPEND);
-            block.push_space();
-        }
-
-        if self.is_inline {
-            block.push_static_atom(tokens::keyword::INLINE);
-            block.push_space();
+        if !self.context_parameters.is_empty() {
+            block.push_renderable(&ContextParameter::render_clause(&self.context_parameters));
+            block.push_new_line();
         }
 
-        if self.is_operator {
-            block.push_static_atom(tokens::keyword::OPERATOR);
-            block.push_space();
-        }
+        block.push_renderable(&self.visibility_modifier);
+        block.push_space();
 
-        if self.is_override {
-            block.push_static_atom(tokens::keyword::OVERRIDE);
+        // canonical order from https://kotlinlang.org/docs/coding-conventions.html#modifiers-order
+        let modifiers = [
+            (self.is_expect, tokens::keyword::EXPECT),
+            (self.is_actual, tokens::keyword::ACTUAL),
+            // final is implicit for functions, so only `open` and `abstract` are rendered
+            (self.inheritance_modifier == MemberInheritanceModifier::Open, tokens::keyword::OPEN),
+            (self.is_abstract(), tokens::keyword::ABSTRACT),
+            (self.is_external, tokens::keyword::EXTERNAL),
+            (self.is_override, tokens::keyword::OVERRIDE),
+            (self.is_tailrec, tokens::keyword::TAILREC),
+            (self.is_suspended, tokens::keyword::SUSPEND),
+            (self.is_inline, tokens::keyword::INLINE),
+            (self.is_infix, tokens::keyword::INFIX),
+            (self.is_operator, tokens::keyword::OPERATOR),
+        ];
+        for (_, keyword) in modifiers.iter().filter(|(flag, _)| *flag) {
+            block.push_static_atom(keyword);
             block.push_space();
         }
 
@@ -143,7 +292,11 @@ impl RenderKotlin for Function {
         }
 
         if let Some(receiver) = &self.receiver {
-            block.push_renderable(receiver);
+            if receiver.requires_parentheses() {
+                block.push_round_brackets(|receiver_code| receiver_code.push_renderable(receiver));
+            } else {
+                block.push_renderable(receiver);
+            }
             block.push_static_atom(tokens::DOT);
         }
         block.push_renderable(&self.name);
@@ -159,9 +312,13 @@ impl RenderKotlin for Function {
             }
         });
 
-        block.push_static_atom(tokens::COLON);
-        block.push_space();
-        block.push_renderable(&self.returns);
+        let can_omit_return_type = self.returns == Type::unit()
+            || self.body.as_ref().is_some_and(FunctionBody::is_expression);
+        if !(self.is_return_type_omitted && can_omit_return_type) {
+            block.push_static_atom(tokens::COLON);
+            block.push_space();
+            block.push_renderable(&self.returns);
+        }
 
         block.push_space();
         block.push_renderable(
@@ -172,9 +329,7 @@ impl RenderKotlin for Function {
 
         if let Some(body) = &self.body {
             block.push_space();
-            block.push_curly_brackets(|inner| {
-                inner.push_renderable(body);
-            });
+            block.push_renderable(body);
         }
     }
 }
@@ -182,7 +337,7 @@ impl RenderKotlin for Function {
 #[cfg(test)]
 mod tests {
     use crate::io::RenderKotlin;
-    use crate::spec::{Annotation, ClassLikeTypeName, CodeBlock, Function, GenericParameter, KDoc, Name, Package, Type, VisibilityModifier};
+    use crate::spec::{Annotation, ClassLikeTypeName, CodeBlock, ContextParameter, Function, FunctionType, GenericParameter, KDoc, MemberInheritanceModifier, Name, Package, Type, VisibilityModifier};
     use crate::spec::function::Parameter;
 
     #[test]
@@ -244,6 +399,59 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_parenthesized_receiver() {
+        let definitely_non_nullable = Function::new("foo")
+            .generic_parameter(GenericParameter::new("T"))
+            .receiver(Type::generic("T").definitely_non_nullable());
+        let function_type = Function::new("foo")
+            .receiver(FunctionType::new(Type::unit()));
+
+        assert_eq!(
+            definitely_non_nullable.render_string(),
+            "public fun <T> (T & kotlin.Any).foo(): kotlin.Unit"
+        );
+        assert_eq!(
+            function_type.render_string(),
+            "public fun (() -> kotlin.Unit).foo(): kotlin.Unit"
+        );
+    }
+
+    #[test]
+    fn test_context_receivers() {
+        let block = Function::new(Name::from("log"))
+            .annotation(Annotation::new(ClassLikeTypeName::top_level(
+                Package::from(vec![]),
+                Name::from("Test"),
+            )))
+            .context_parameter(ContextParameter::receiver(Type::string()))
+            .context_parameter(ContextParameter::receiver(Type::int()));
+
+        assert_eq!(
+            "@Test()\ncontext(kotlin.String, kotlin.Int)\npublic fun log(): kotlin.Unit",
+            block.render_string()
+        )
+    }
+
+    #[test]
+    fn test_context_parameters() {
+        let block = Function::new(Name::from("log"))
+            .context_parameter(ContextParameter::named("text", Type::string()));
+
+        assert_eq!(
+            "context(text: kotlin.String)\npublic fun log(): kotlin.Unit",
+            block.render_string()
+        )
+    }
+
+    #[test]
+    fn test_mixed_context_parameters_validation() {
+        let function = Function::new(Name::from("log"))
+            .context_parameter(ContextParameter::receiver(Type::string()));
+        assert!(function.clone().validate().is_ok());
+        assert!(function.context_parameter(ContextParameter::named("text", Type::string())).validate().is_err());
+    }
+
     #[test]
     fn test_override() {
         let block = Function::new(Name::from("box"))
@@ -257,6 +465,153 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_expression_body() {
+        let function = Function::new("id")
+            .returns(Type::string())
+            .expression_body(CodeBlock::statement("value"));
+
+        assert_eq!(
+            "public fun id(): kotlin.String = value",
+            function.render_string()
+        )
+    }
+
+    #[test]
+    fn test_multiline_expression_body() {
+        let mut expression = CodeBlock::statement("items");
+        expression.push_indent();
+        expression.push_statement(".map { it.name }");
+        expression.push_statement(".joinToString()");
+        expression.push_unindent();
+
+        let function = Function::new("names")
+            .returns(Type::string())
+            .expression_body(expression);
+
+        assert_eq!(
+            "public fun names(): kotlin.String =\n    items\n        .map { it.name }\n        .joinToString()",
+            function.render_string()
+        )
+    }
+
+    #[test]
+    fn test_omit_return_type() {
+        let expression = Function::new("id")
+            .returns(Type::string())
+            .omit_return_type(true)
+            .expression_body(CodeBlock::atom("value"));
+        assert_eq!("public fun id() = value", expression.render_string());
+
+        let unit = Function::new("run")
+            .omit_return_type(true)
+            .body(CodeBlock::statement("println()"));
+        assert_eq!("public fun run() {\n    println()\n}", unit.render_string());
+
+        let block = Function::new("id")
+            .returns(Type::string())
+            .omit_return_type(true)
+            .body(CodeBlock::statement("return value"));
+        assert_eq!(
+            "public fun id(): kotlin.String {\n    return value\n}",
+            block.render_string()
+        );
+    }
+
+    #[test]
+    fn test_parameter_kdoc() {
+        let function = Function::new("greet")
+            .kdoc("Greets the user")
+            .parameter(Parameter::new("name", Type::string()).kdoc("Name of the user"))
+            .parameter(Parameter::new("times", Type::int()))
+            .parameter(Parameter::new("loud", Type::boolean()).kdoc("Whether to shout"));
+
+        assert_eq!(
+            "/**\n * Greets the user\n * @param name Name of the user\n * @param loud Whether to shout\n */\npublic fun greet(name: kotlin.String, times: kotlin.Int, loud: kotlin.Boolean): kotlin.Unit",
+            function.render_string()
+        );
+
+        let undocumented = Function::new("greet")
+            .parameter(Parameter::new("name", Type::string()).kdoc("Name of the user"));
+
+        assert_eq!(
+            "/**\n * @param name Name of the user\n */\npublic fun greet(name: kotlin.String): kotlin.Unit",
+            undocumented.render_string()
+        );
+    }
+
+    #[test]
+    fn test_modifiers_order() {
+        let function = Function::new("combine")
+            .operator(true)
+            .infix(true)
+            .inline(true)
+            .suspended(true)
+            .tailrec(true)
+            .overrides(true)
+            .external(true)
+            .actual(true)
+            .inheritance_modifier(MemberInheritanceModifier::Open);
+
+        assert_eq!(
+            "public actual open external override tailrec suspend inline infix operator fun combine(): kotlin.Unit",
+            function.render_string()
+        )
+    }
+
+    #[test]
+    fn test_abstract() {
+        let function = Function::new("load")
+            .inheritance_modifier(MemberInheritanceModifier::Abstract)
+            .returns(Type::string());
+
+        assert_eq!(
+            "public abstract fun load(): kotlin.String",
+            function.render_string()
+        );
+        assert!(function.validate().is_ok());
+        assert!(function.body(CodeBlock::statement("return \"\"")).validate().is_err());
+    }
+
+    #[test]
+    fn test_expect_actual_are_exclusive() {
+        let function = Function::new("platform")
+            .expect(true)
+            .actual(true);
+
+        assert_eq!("public actual fun platform(): kotlin.Unit", function.render_string());
+        assert_eq!(
+            "public expect fun platform(): kotlin.Unit",
+            function.actual(true).expect(true).render_string()
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        let external = Function::new("nativeHash")
+            .external(true)
+            .returns(Type::int());
+        assert!(external.validate().is_ok());
+        assert!(external.body(CodeBlock::statement("return 0")).validate().is_err());
+
+        let infix = Function::new("to").infix(true);
+        assert!(infix.clone().validate().is_err());
+        assert!(infix.clone().parameter(Parameter::new("other", Type::int())).validate().is_ok());
+        assert!(
+            infix.clone()
+                .parameter(Parameter::new("other", Type::int()))
+                .parameter(Parameter::new("another", Type::int()))
+                .validate().is_err()
+        );
+        assert!(infix.parameter(Parameter::new("other", Type::int()).vararg(true)).validate().is_err());
+
+        let tailrec = Function::new("loop")
+            .tailrec(true)
+            .body(CodeBlock::statement("loop()"));
+        assert!(tailrec.clone().validate().is_ok());
+        assert!(tailrec.inheritance_modifier(MemberInheritanceModifier::Open).validate().is_err());
+    }
+
     #[test]
     fn test_kdoc() {
         let block = Function::new(Name::from("box"))
@@ -0,0 +1,53 @@
+use crate::io::RenderKotlin;
+use crate::spec::CodeBlock;
+use crate::spec::code_block::CodeBlockNode;
+use crate::tokens;
+
+/// Body of functions and property accessors, either `{ ... }` or `= ...`
+#[derive(Debug, Clone)]
+pub(crate) enum FunctionBody {
+    Block(CodeBlock),
+    Expression(CodeBlock),
+}
+
+impl FunctionBody {
+    /// Creates expression body, trailing new lines are dropped since expression is followed by declaration end
+    pub(crate) fn expression<CodeBlockLike: Into<CodeBlock>>(expression: CodeBlockLike) -> FunctionBody {
+        let mut expression = expression.into();
+        while matches!(expression.nodes.last(), Some(CodeBlockNode::NewLine)) {
+            expression.nodes.pop();
+        }
+        FunctionBody::Expression(expression)
+    }
+
+    pub(crate) fn is_expression(&self) -> bool {
+        matches!(self, FunctionBody::Expression(_))
+    }
+}
+
+impl RenderKotlin for FunctionBody {
+    fn render_into(&self, block: &mut CodeBlock) {
+        match self {
+            FunctionBody::Block(code) => {
+                block.push_curly_brackets(|inner| {
+                    inner.push_renderable(code);
+                });
+            }
+            FunctionBody::Expression(expression) => {
+                block.push_static_atom(tokens::ASSIGN);
+                let is_multiline = expression.nodes.iter()
+                    .any(|node| matches!(node, CodeBlockNode::NewLine));
+                if is_multiline {
+                    // moves whole expression to the next line, so continuation lines align with the first one
+                    block.push_new_line();
+                    block.push_indent();
+                    block.push_renderable(expression);
+                    block.push_unindent();
+                } else {
+                    block.push_space();
+                    block.push_renderable(expression);
+                }
+            }
+        }
+    }
+}
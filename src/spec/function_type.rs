@@ -1,11 +1,30 @@
 use crate::io::RenderKotlin;
-use crate::spec::{CodeBlock, Type};
+use crate::spec::{CodeBlock, ContextParameter, Name, Type};
 use crate::tokens;
 
-#[derive(PartialEq, Debug, Clone)]
+/// Parameter of [FunctionType], name is optional and used only for documentation purposes
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone)]
+struct FunctionTypeParameter {
+    name: Option<Name>,
+    parameter_type: Type,
+}
+
+impl RenderKotlin for FunctionTypeParameter {
+    fn render_into(&self, block: &mut CodeBlock) {
+        if let Some(name) = &self.name {
+            block.push_renderable(name);
+            block.push_static_atom(tokens::COLON);
+            block.push_space();
+        }
+        block.push_renderable(&self.parameter_type);
+    }
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone)]
 pub struct FunctionType {
     receiver: Box<Option<Type>>,
-    parameters: Vec<Type>,
+    context_receivers: Vec<ContextParameter>,
+    parameters: Vec<FunctionTypeParameter>,
     returns: Box<Type>,
     is_suspended: bool
 }
@@ -15,6 +34,7 @@ impl FunctionType {
     pub fn new<TypeLike: Into<Type>>(returns: TypeLike) -> Self {
         FunctionType {
             receiver: Box::new(None),
+            context_receivers: Vec::new(),
             parameters: Vec::new(),
             returns: Box::new(returns.into()),
             is_suspended: false
@@ -26,8 +46,32 @@ impl FunctionType {
         self
     }
 
+    /// Adds context receiver, e.g. `context(Logger) () -> kotlin.Unit`.
+    /// Function types only support unnamed context receivers.
+    pub fn context_receiver<TypeLike: Into<Type>>(mut self, context_receiver: TypeLike) -> Self {
+        self.context_receivers.push(ContextParameter::receiver(context_receiver));
+        self
+    }
+
     pub fn parameter<TypeLike: Into<Type>>(mut self, parameters: TypeLike) -> Self {
-        self.parameters.push(parameters.into());
+        self.parameters.push(FunctionTypeParameter {
+            name: None,
+            parameter_type: parameters.into(),
+        });
+        self
+    }
+
+    /// Adds parameter with a name, e.g. `(userId: kotlin.String) -> kotlin.Unit`.
+    /// Named and unnamed parameters can be mixed.
+    pub fn named_parameter<NameLike: Into<Name>, TypeLike: Into<Type>>(
+        mut self,
+        name: NameLike,
+        parameter_type: TypeLike,
+    ) -> Self {
+        self.parameters.push(FunctionTypeParameter {
+            name: Some(name.into()),
+            parameter_type: parameter_type.into(),
+        });
         self
     }
 
@@ -40,13 +84,52 @@ impl FunctionType {
         self.is_suspended = flag;
         self
     }
+
+    /// Receiver type, e.g. `A` in `A.() -> B`
+    pub fn receiver_type(&self) -> Option<&Type> {
+        self.receiver.as_ref().as_ref()
+    }
+
+    /// Types of context receivers, e.g. `A` in `context(A) () -> B`
+    pub fn context_receiver_types(&self) -> impl Iterator<Item = &Type> {
+        self.context_receivers.iter().map(ContextParameter::parameter_type)
+    }
+
+    /// Types of parameters in order of declaration
+    pub fn parameter_types(&self) -> impl Iterator<Item = &Type> {
+        self.parameters.iter().map(|parameter| &parameter.parameter_type)
+    }
+
+    /// Return type, e.g. `B` in `(A) -> B`
+    pub fn return_type(&self) -> &Type {
+        &self.returns
+    }
+
+    /// Checks if the function type is marked as `suspend`
+    pub fn is_suspended(&self) -> bool {
+        self.is_suspended
+    }
+
+    /// Replaces every nested type (context receivers, receiver, parameters and return type) with the result of [transform]
+    pub(crate) fn map_types<F: FnMut(Type) -> Type>(mut self, transform: &mut F) -> FunctionType {
+        self.context_receivers = self.context_receivers.into_iter()
+            .map(|context_receiver| context_receiver.map_type(transform))
+            .collect();
+        self.receiver = Box::new(self.receiver.map(&mut *transform));
+        for parameter in &mut self.parameters {
+            let parameter_type = std::mem::replace(&mut parameter.parameter_type, Type::unit());
+            parameter.parameter_type = transform(parameter_type);
+        }
+        *self.returns = transform(*self.returns);
+        self
+    }
 }
 
 impl RenderKotlin for FunctionType {
     fn render_into(&self, block: &mut CodeBlock) {
-        if let Some(receiver) = &*self.receiver {
-            block.push_renderable(receiver);
-            block.push_static_atom(tokens::DOT)
+        if !self.context_receivers.is_empty() {
+            block.push_renderable(&ContextParameter::render_clause(&self.context_receivers));
+            block.push_space();
         }
 
         if self.is_suspended {
@@ -54,6 +137,15 @@ impl RenderKotlin for FunctionType {
             block.push_space()
         }
 
+        if let Some(receiver) = &*self.receiver {
+            if receiver.requires_parentheses() {
+                block.push_round_brackets(|receiver_code| receiver_code.push_renderable(receiver));
+            } else {
+                block.push_renderable(receiver);
+            }
+            block.push_static_atom(tokens::DOT)
+        }
+
         block.push_round_brackets(|parameters_code| {
             parameters_code.push_comma_separated(
                 &self.parameters
@@ -81,6 +173,31 @@ mod tests {
         assert_eq!(lambda_type.render_string(), "(kotlin.String, kotlin.Boolean) -> kotlin.Int");
     }
 
+    #[test]
+    fn render_lambda_type_with_named_parameters() {
+        let lambda_type = FunctionType::new(Type::unit())
+            .named_parameter("userId", Type::string())
+            .parameter(Type::int())
+            .named_parameter("retry", Type::boolean());
+        assert_eq!(
+            lambda_type.render_string(),
+            "(userId: kotlin.String, kotlin.Int, retry: kotlin.Boolean) -> kotlin.Unit"
+        );
+    }
+
+    #[test]
+    fn render_lambda_type_with_context_receivers() {
+        let lambda_type = FunctionType::new(Type::unit())
+            .context_receiver(Type::string())
+            .context_receiver(Type::int())
+            .receiver(Type::boolean())
+            .suspended(true);
+        assert_eq!(
+            lambda_type.render_string(),
+            "context(kotlin.String, kotlin.Int) suspend kotlin.Boolean.() -> kotlin.Unit"
+        );
+    }
+
     #[test]
     fn render_lambda_type_with_receiver() {
         let lambda_type = FunctionType::new(Type::int())
@@ -97,4 +214,12 @@ mod tests {
             .suspended(true);
         assert_eq!(lambda_type.render_string(), "suspend (kotlin.String, kotlin.Boolean) -> kotlin.Int");
     }
+
+    #[test]
+    fn render_lambda_type_with_suspended_receiver() {
+        let lambda_type = FunctionType::new(Type::unit())
+            .receiver(Type::string())
+            .suspended(true);
+        assert_eq!(lambda_type.render_string(), "suspend kotlin.String.() -> kotlin.Unit");
+    }
 }
\ No newline at end of file
@@ -4,8 +4,9 @@ use crate::tokens;
 
 /// Type of generic [parameter invariance](https://kotlinlang.org/docs/generics.html#variance-and-wildcards-in-java).
 ///
-/// Conventionally possibly non-invariant generics are expressed via `Option<GenericInvariance>`
-#[derive(Debug, Clone)]
+/// Conventionally possibly non-invariant generics are expressed via `Option<GenericInvariance>`.
+/// Used both for declaration-site variance of [crate::spec::GenericParameter] and use-site variance of [crate::spec::TypeProjection].
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone)]
 pub enum GenericInvariance {
     /// Corresponds to [tokens::keyword::IN]
     In,
@@ -1,5 +1,5 @@
 use crate::io::RenderKotlin;
-use crate::spec::CodeBlock;
+use crate::spec::{CodeBlock, Parameter};
 use crate::tokens;
 
 /// Represents a Kotlin documentation comment in KDoc format.
@@ -72,6 +72,25 @@ impl RenderKotlin for KdocSlot {
 }
 
 impl KdocSlot {
+    /// Creates copy of this slot with `@param` tags of documented [parameters] appended.
+    pub(crate) fn with_parameter_tags(&self, parameters: &[Parameter]) -> KdocSlot {
+        self.with_tags(parameters.iter().filter_map(Parameter::kdoc_tag))
+    }
+
+    /// Creates copy of this slot with [tags] appended.
+    pub(crate) fn with_tags<Tags: IntoIterator<Item = KDoc>>(&self, tags: Tags) -> KdocSlot {
+        let mut slot = self.clone();
+        for tag in tags {
+            slot.merge(tag);
+        }
+        slot
+    }
+
+    /// Content of the KDoc, [None] if nothing was added
+    pub(crate) fn content(&self) -> Option<&str> {
+        self.0.as_ref().map(|kdoc| kdoc.content.as_str())
+    }
+
     /// Merges [other] into this KDoc slot.
     /// If [None] sets [other] as current value.
     pub(crate) fn merge(&mut self, other: KDoc) {
@@ -3,7 +3,7 @@ use crate::spec::CodeBlock;
 use crate::tokens;
 
 /// Inheritance modifiers applicable to class / file members
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum MemberInheritanceModifier {
     /// `open` modifier
     Open,
@@ -0,0 +1,76 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use crate::spec::ClassMember;
+
+/// Comparator of [MemberOrder::Custom] strategy
+pub type MemberComparator = Arc<dyn Fn(&ClassMember, &ClassMember) -> Ordering + Send + Sync>;
+
+/// Strategy of ordering members in class or companion object body,
+/// see [crate::spec::Class::member_order] and [crate::spec::CompanionObject::member_order].
+/// Companion object is always rendered last regardless of strategy.
+///
+/// # Examples
+/// ```rust
+/// use kotlin_poet_rs::io::RenderKotlin;
+/// use kotlin_poet_rs::spec::{Class, ClassMember, Function, MemberOrder, Property, Type};
+///
+/// let priority = vec!["name".to_string()];
+/// let class = Class::new("Person")
+///     .function(Function::new("greet"))
+///     .property(Property::new("age", Type::int()))
+///     .property(Property::new("name", Type::string()))
+///     .function(Function::new("age"))
+///     .member_order(MemberOrder::custom(move |a: &ClassMember, b: &ClassMember| {
+///         let is_prioritized = |member: &ClassMember| member.name()
+///             .is_some_and(|name| priority.contains(&name.render_string()));
+///         a.compare_by_conventions(b)
+///             .then_with(|| is_prioritized(b).cmp(&is_prioritized(a)))
+///             .then_with(|| a.name().cmp(&b.name()))
+///     }));
+///
+/// assert_eq!(
+///     class.render_string(),
+///     "public final class Person {\n\n    public final val name: kotlin.String\n    public final val age: kotlin.Int\n    public fun age(): kotlin.Unit \n    public fun greet(): kotlin.Unit \n}"
+/// );
+/// ```
+#[derive(Clone, Default)]
+pub enum MemberOrder {
+    /// Members are rendered in order they were added
+    #[default]
+    Insertion,
+    /// Members are grouped according to [ClassMember::compare_by_conventions], keeping insertion order within group
+    KotlinConventions,
+    /// Members are sorted with custom comparator, sort is stable
+    Custom(MemberComparator),
+}
+
+impl MemberOrder {
+    /// Creates [MemberOrder::Custom] strategy, [comparator] may capture its environment
+    pub fn custom<F>(comparator: F) -> MemberOrder
+    where
+        F: Fn(&ClassMember, &ClassMember) -> Ordering + Send + Sync + 'static,
+    {
+        MemberOrder::Custom(Arc::new(comparator))
+    }
+
+    /// Sorts members in place according to the strategy
+    pub(crate) fn sort(&self, members: &mut [ClassMember]) {
+        match self {
+            MemberOrder::Insertion => {}
+            MemberOrder::KotlinConventions => members.sort_by(ClassMember::compare_by_conventions),
+            MemberOrder::Custom(comparator) => members.sort_by(|a, b| comparator(a, b)),
+        }
+    }
+}
+
+impl Debug for MemberOrder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MemberOrder::Insertion => f.write_str("Insertion"),
+            MemberOrder::KotlinConventions => f.write_str("KotlinConventions"),
+            MemberOrder::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
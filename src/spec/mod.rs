@@ -6,25 +6,43 @@ mod r#type;
 mod class_like_type;
 mod code_block;
 mod function;
+mod function_body;
 mod visibility_modifier;
 mod member_inheritance_modifier;
 mod property;
 mod function_type;
+mod nullable_type;
+mod definitely_non_nullable_type;
+mod annotated_type;
 mod type_alias;
 mod class;
 mod class_inheritance_modifier;
+mod class_kind;
+mod class_modifier;
 mod file;
 mod argument;
 mod primary_constructor;
 mod secondary_constructor;
 mod companion_object;
+mod enum_entry;
+mod supertype;
+mod object_expression;
+mod class_member;
+mod class_member_kind;
+mod member_order;
 mod generic_parameter;
 mod generic_invariance;
 mod parameter;
 mod annotation;
 mod annotation_target;
+mod annotation_class_target;
+mod annotation_retention;
 mod comment;
 mod kdoc;
+mod type_parser;
+mod type_projection;
+mod context_parameter;
+mod to_kotlin_type;
 
 pub use package::Package;
 pub use class_like_type_name::ClassLikeTypeName;
@@ -38,18 +56,41 @@ pub use visibility_modifier::VisibilityModifier;
 pub use member_inheritance_modifier::MemberInheritanceModifier;
 pub use property::{Property, PropertyGetter, PropertySetter};
 pub use function_type::FunctionType;
+pub use nullable_type::NullableType;
+pub use definitely_non_nullable_type::DefinitelyNonNullableType;
+pub use annotated_type::AnnotatedType;
 pub use type_alias::TypeAlias;
 pub use class::Class;
 pub use class_inheritance_modifier::ClassInheritanceModifier;
+pub use class_kind::ClassKind;
+pub use class_modifier::ClassModifier;
 pub use file::KotlinFile;
 pub use argument::Argument;
 pub use primary_constructor::PrimaryConstructor;
 pub use secondary_constructor::SecondaryConstructor;
 pub use companion_object::CompanionObject;
+pub use enum_entry::EnumEntry;
+pub use supertype::Supertype;
+pub use object_expression::ObjectExpression;
+pub use class_member::ClassMember;
+pub use class_member_kind::ClassMemberKind;
+pub use member_order::{MemberComparator, MemberOrder};
 pub use generic_invariance::GenericInvariance;
 pub use generic_parameter::GenericParameter;
 pub use parameter::Parameter;
 pub use annotation::Annotation;
 pub use annotation_target::AnnotationTarget;
+pub use annotation_class_target::AnnotationClassTarget;
+pub use annotation_retention::AnnotationRetention;
 pub use comment::Comment;
 pub use kdoc::KDoc;
+pub use type_projection::TypeProjection;
+pub use context_parameter::ContextParameter;
+pub use to_kotlin_type::ToKotlinType;
+
+#[cfg(any(feature = "catalog-kotlinx-coroutines", feature = "catalog-kotlinx-serialization", feature = "catalog-java"))]
+pub(crate) use r#type::fn_basic_type_factory;
+#[cfg(any(feature = "catalog-kotlinx-coroutines", feature = "catalog-kotlinx-serialization"))]
+pub(crate) use r#type::fn_generic_type_factory;
+#[cfg(any(feature = "catalog-kotlinx-serialization", feature = "catalog-androidx"))]
+pub(crate) use annotation::fn_annotation_factory;
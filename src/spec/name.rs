@@ -1,9 +1,10 @@
+use std::cmp::Ordering;
 use std::str::FromStr;
 
 use crate::io::RenderKotlin;
 use crate::spec::CodeBlock;
 use crate::tokens;
-use crate::util::{SemanticConversionError, yolo_from_str};
+use crate::util::{compare_rendered, SemanticConversionError, yolo_from_str};
 
 /// Kotlin identifier name, automatically escaped with backticks if it contains escapable tokens
 ///
@@ -19,12 +20,28 @@ use crate::util::{SemanticConversionError, yolo_from_str};
 /// let escaped_name = Name::from("Foo Bar");
 /// assert_eq!(escaped_name.render_string(), "`Foo Bar`")
 /// ```
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub struct Name {
     value: String,
     should_be_escaped: bool
 }
 
+/// Names are ordered as they render, escaped names start with a backtick
+impl Ord for Name {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if !self.should_be_escaped && !other.should_be_escaped {
+            return self.value.cmp(&other.value);
+        }
+        compare_rendered(self, other)
+    }
+}
+
+impl PartialOrd for Name {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 yolo_from_str!(Name);
 /// Creates new [Name] from [&str]
 impl FromStr for Name {
@@ -42,7 +59,7 @@ impl FromStr for Name {
         if s.chars().any(|ch| tokens::NAME_DISALLOWED_TOKENS.contains(ch)) {
             return Err(
                 SemanticConversionError::new(
-                    format!("`{} contains tokens not allowed in kotlin identifier names`", s)
+                    format!("`{}` contains tokens not allowed in kotlin identifier names", s)
                         .as_str()
                 )
             )
@@ -72,9 +89,9 @@ impl RenderKotlin for Name {
     }
 }
 
-impl Into<String> for Name {
-    fn into(self) -> String {
-        self.value
+impl From<Name> for String {
+    fn from(name: Name) -> Self {
+        name.value
     }
 }
 
@@ -104,12 +121,19 @@ mod tests {
     #[test]
     fn test_name_with_disallowed_characters() {
         let name = Name::from_str("Foo/Bar");
-        assert!(matches!(name, Err(_)));
+        assert!(name.is_err());
+    }
+
+    #[test]
+    fn test_ordering_by_render() {
+        let mut names = vec![Name::from("b"), Name::from("a b"), Name::from("a"), Name::from("B")];
+        names.sort();
+        assert_eq!(names, vec![Name::from("B"), Name::from("a b"), Name::from("a"), Name::from("b")]);
     }
 
     #[test]
     fn test_empty_name() {
         let name = Name::from_str("");
-        assert!(matches!(name, Err(_)));
+        assert!(name.is_err());
     }
 }
\ No newline at end of file
@@ -0,0 +1,23 @@
+use crate::spec::Type;
+
+/// Nullable version of a type which can't carry nullability itself (e.g. `T?`, `(() -> kotlin.Unit)?`).
+/// Can only be created via [Type::nullable], so it never wraps class like or already nullable types.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone)]
+pub struct NullableType {
+    inner: Box<Type>,
+}
+
+impl NullableType {
+    pub(crate) fn new(inner: Type) -> NullableType {
+        NullableType { inner: Box::new(inner) }
+    }
+
+    /// Type made nullable
+    pub fn inner(&self) -> &Type {
+        &self.inner
+    }
+
+    pub(crate) fn into_inner(self) -> Type {
+        *self.inner
+    }
+}
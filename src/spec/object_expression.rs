@@ -0,0 +1,178 @@
+use crate::io::RenderKotlin;
+use crate::spec::{CodeBlock, Function, Property, Supertype};
+use crate::spec::class::ClassMemberNode;
+use crate::tokens;
+use crate::util::ValidationError;
+
+/// Anonymous [object expression](https://kotlinlang.org/docs/object-declarations.html#object-expressions),
+/// e.g. `object: Callback { ... }`.
+///
+/// Converts into [CodeBlock], so it can be used as function body, property initializer, argument e.t.c.
+///
+/// # Examples
+/// ```rust
+/// use kotlin_poet_rs::io::RenderKotlin;
+/// use kotlin_poet_rs::spec::{ClassLikeTypeName, CodeBlock, Function, ObjectExpression, Property};
+///
+/// let callback = ObjectExpression::new()
+///     .inherits(ClassLikeTypeName::from("a.Callback"))
+///     .function(
+///         Function::new("onDone")
+///             .overrides(true)
+///             .body(CodeBlock::statement("println(\"done\")"))
+///     );
+///
+/// let property = Property::new("callback", ClassLikeTypeName::from("a.Callback"))
+///     .initializer(callback);
+///
+/// assert_eq!(
+///     property.render_string(),
+///     "public final val callback: a.Callback = object: a.Callback {\n    public override fun onDone(): kotlin.Unit {\n        println(\"done\")\n    }\n}"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct ObjectExpression {
+    supertypes: Vec<Supertype>,
+    member_nodes: Vec<ClassMemberNode>,
+}
+
+impl ObjectExpression {
+    /// Creates object expression without supertypes and members
+    pub fn new() -> ObjectExpression {
+        ObjectExpression {
+            supertypes: Vec::new(),
+            member_nodes: Vec::new(),
+        }
+    }
+
+    /// Adds supertype of the object. Supertypes will appear in order this method is called.
+    pub fn inherits<SupertypeLike: Into<Supertype>>(mut self, supertype: SupertypeLike) -> ObjectExpression {
+        self.supertypes.push(supertype.into());
+        self
+    }
+
+    /// Adds property to the object. Members will appear in order they are added.
+    pub fn property(mut self, property: Property) -> ObjectExpression {
+        self.member_nodes.push(ClassMemberNode::Property(property));
+        self
+    }
+
+    /// Adds function to the object. Members will appear in order they are added.
+    pub fn function(mut self, function: Function) -> ObjectExpression {
+        self.member_nodes.push(ClassMemberNode::Function(function));
+        self
+    }
+
+    /// Adds init block to the object. Members will appear in order they are added.
+    pub fn init<CodeBlockLike: Into<CodeBlock>>(mut self, block: CodeBlockLike) -> ObjectExpression {
+        self.member_nodes.push(ClassMemberNode::InitBlock(block.into()));
+        self
+    }
+
+    /// Checks that members of the object are consistent, see [crate::spec::Class::validate]
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        ClassMemberNode::validate_all(&self.member_nodes, false, "object")
+    }
+}
+
+impl Default for ObjectExpression {
+    fn default() -> Self {
+        ObjectExpression::new()
+    }
+}
+
+impl RenderKotlin for ObjectExpression {
+    fn render_into(&self, block: &mut CodeBlock) {
+        block.push_static_atom(tokens::keyword::OBJECT);
+        block.push_space();
+        if !self.supertypes.is_empty() {
+            block.pop_space();
+            block.push_static_atom(tokens::COLON);
+            block.push_space();
+            block.push_comma_separated(&self.supertypes);
+            block.push_space();
+        }
+
+        // body is indented relative to the line object starts on, not to the enclosing continuation indent
+        block.push_line_aligned(|aligned_code| {
+            aligned_code.push_curly_brackets(|body_code| {
+                for node in &self.member_nodes {
+                    body_code.push_renderable(node);
+                    body_code.push_new_line();
+                }
+            });
+        });
+    }
+}
+
+impl From<ObjectExpression> for CodeBlock {
+    fn from(value: ObjectExpression) -> Self {
+        let mut block = CodeBlock::empty();
+        block.push_renderable(&value);
+        block
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::RenderKotlin;
+    use crate::spec::{Argument, Class, ClassLikeTypeName, CodeBlock, Function, ObjectExpression, Property, Supertype, Type};
+
+    #[test]
+    fn render_empty() {
+        assert_eq!(ObjectExpression::new().render_string(), "object {\n}");
+    }
+
+    #[test]
+    fn render_with_supertypes_and_members() {
+        let object = ObjectExpression::new()
+            .inherits(Supertype::class(
+                ClassLikeTypeName::from("a.Base"),
+                vec![Argument::new_positional(CodeBlock::atom("1"))],
+            ))
+            .inherits(ClassLikeTypeName::from("a.Listener"))
+            .property(Property::new("count", Type::int()).initializer("0"));
+
+        assert_eq!(
+            object.render_string(),
+            "object: a.Base(1), a.Listener {\n    public final val count: kotlin.Int = 0\n}"
+        );
+    }
+
+    #[test]
+    fn use_as_function_body() {
+        let mut body = CodeBlock::atom("return ");
+        body.push_renderable(&ObjectExpression::new().inherits(ClassLikeTypeName::from("a.Listener")));
+        body.push_new_line();
+        let function = Function::new("listener")
+            .returns(ClassLikeTypeName::from("a.Listener"))
+            .body(body);
+
+        assert_eq!(
+            function.render_string(),
+            "public fun listener(): a.Listener {\n    return object: a.Listener {\n    }\n}"
+        );
+    }
+
+    #[test]
+    fn align_with_property_in_class() {
+        let class = Class::new("Screen").property(
+            Property::new("listener", ClassLikeTypeName::from("a.Listener"))
+                .initializer(ObjectExpression::new().inherits(ClassLikeTypeName::from("a.Listener")).function(Function::new("onEvent").body(CodeBlock::statement("println()"))))
+        );
+
+        assert_eq!(
+            class.render_string(),
+            "public final class Screen {\n\n    public final val listener: a.Listener = object: a.Listener {\n        public fun onEvent(): kotlin.Unit {\n            println()\n        }\n    }\n}"
+        );
+    }
+
+    #[test]
+    fn validate_members() {
+        let listener = ObjectExpression::new().inherits(ClassLikeTypeName::from("a.Listener"));
+        let on_event = Function::new("onEvent");
+
+        assert!(listener.clone().function(on_event.clone()).validate().is_ok());
+        assert!(listener.function(on_event.infix(true)).validate().is_err());
+    }
+}
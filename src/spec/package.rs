@@ -1,8 +1,9 @@
-use std::path::PathBuf;
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
 use crate::io::RenderKotlin;
 use crate::spec::{CodeBlock, Name};
 use crate::tokens::DOT;
-use crate::util::{yolo_from_str, SemanticConversionError};
+use crate::util::{compare_rendered, yolo_from_str, SemanticConversionError};
 use std::str::FromStr;
 
 /// Fully qualified package name, may be parsed from [&str]
@@ -54,11 +55,24 @@ use std::str::FromStr;
 ///     ""
 /// );
 /// ```
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub struct Package {
     pub(crate) parts: Vec<Name>,
 }
 
+/// Packages are ordered as they render
+impl Ord for Package {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare_rendered(self, other)
+    }
+}
+
+impl PartialOrd for Package {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl Package {
     /// Creates new package from [Name] parts
     pub fn from(names: Vec<Name>) -> Package {
@@ -72,8 +86,8 @@ impl Package {
 
     /// Converts package to Java-like folder structure path
     #[cfg(feature = "experimental")]
-    pub fn to_path(&self) -> PathBuf {
-        let mut buf = PathBuf::new();
+    pub fn to_path(&self) -> std::path::PathBuf {
+        let mut buf = std::path::PathBuf::new();
         for part in &self.parts {
             let part_str: String = part.clone().into();
             buf.push(part_str)
@@ -85,6 +99,84 @@ impl Package {
     pub(crate) fn is_root(&self) -> bool {
         self.parts.is_empty()
     }
+
+    /// Iterates over package segments, for example `io`, `github`, `lexadiky` for `io.github.lexadiky`
+    pub fn segments(&self) -> std::slice::Iter<'_, Name> {
+        self.parts.iter()
+    }
+
+    /// Returns parent package, for example `io.github` for `io.github.lexadiky`.
+    /// Returns [None] for root package.
+    pub fn parent(&self) -> Option<Package> {
+        if self.is_root() {
+            return None;
+        }
+
+        Some(Package::from(self.parts[..self.parts.len() - 1].to_vec()))
+    }
+
+    /// Returns package nested in this one, for example `io.github.lexadiky` for `io.github` and `lexadiky`
+    pub fn child<NameLike: Into<Name>>(&self, name: NameLike) -> Package {
+        let mut parts = self.parts.clone();
+        parts.push(name.into());
+        Package::from(parts)
+    }
+
+    /// Returns package with all segments of [other] appended to this one.
+    /// Could be used to relocate relative package structure, see [Package::relativize].
+    pub fn join(&self, other: &Package) -> Package {
+        let mut parts = self.parts.clone();
+        parts.extend(other.parts.iter().cloned());
+        Package::from(parts)
+    }
+
+    /// Checks if this package is nested (directly or transitively) in [other].
+    /// Package is not considered to be subpackage of itself, every non-root package is subpackage of root.
+    pub fn is_subpackage_of(&self, other: &Package) -> bool {
+        self.parts.len() > other.parts.len() && self.parts.starts_with(&other.parts)
+    }
+
+    /// Returns path from [base] to this package, for example `lexadiky.model` for `io.github.lexadiky.model` and `io.github`.
+    /// Returns [None] if this package is not [base] or one of its subpackages.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use kotlin_poet_rs::spec::Package;
+    ///
+    /// let package = Package::from_str("io.github.lexadiky.model").unwrap();
+    /// let relative = package.relativize(&Package::from_str("io.github").unwrap()).unwrap();
+    ///
+    /// assert_eq!(
+    ///     Package::from_str("com.acme.generated").unwrap().join(&relative),
+    ///     Package::from_str("com.acme.generated.lexadiky.model").unwrap()
+    /// );
+    /// ```
+    pub fn relativize(&self, base: &Package) -> Option<Package> {
+        if !self.parts.starts_with(&base.parts) {
+            return None;
+        }
+
+        Some(Package::from(self.parts[base.parts.len()..].to_vec()))
+    }
+
+    /// Returns the longest package both this package and [other] are nested in or equal to.
+    /// Returns root package if they have no common segments.
+    pub fn common_prefix(&self, other: &Package) -> Package {
+        Package::from(
+            self.parts.iter()
+                .zip(other.parts.iter())
+                .take_while(|(left, right)| left == right)
+                .map(|(left, _)| left.clone())
+                .collect()
+        )
+    }
+}
+
+impl Display for Package {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.render_string().as_str())
+    }
 }
 
 yolo_from_str!(Package);
@@ -115,6 +207,7 @@ impl RenderKotlin for Package {
 mod tests {
     use crate::io::RenderKotlin;
     use crate::spec::{Name, Package};
+    #[cfg(feature = "experimental")]
     use std::path::PathBuf;
     use std::str::FromStr;
 
@@ -140,12 +233,80 @@ mod tests {
         assert_eq!(package.render_string(), "io.github.lexadiky");
     }
 
+    #[test]
+    fn ordering_by_render() {
+        let mut packages: Vec<Package> = vec![
+            "a.c".parse().unwrap(),
+            "a-b.c".parse().unwrap(),
+            "a.b".parse().unwrap(),
+        ];
+        packages.sort();
+        assert_eq!(
+            packages.iter().map(|it| it.render_string()).collect::<Vec<_>>(),
+            vec!["`a-b`.c", "a.b", "a.c"]
+        );
+    }
+
     #[test]
     fn render_empty() {
         let package: Package = Package::from(vec![]);
         assert_eq!(package.render_string(), "");
     }
 
+    #[test]
+    fn segments() {
+        let package: Package = "io.github.lexadiky".parse().unwrap();
+        assert_eq!(
+            package.segments().cloned().collect::<Vec<_>>(),
+            vec![Name::from("io"), Name::from("github"), Name::from("lexadiky")]
+        );
+    }
+
+    #[test]
+    fn parent_and_child() {
+        let package: Package = "io.github".parse().unwrap();
+        assert_eq!(package.parent(), Some("io".parse().unwrap()));
+        assert_eq!(package.parent().unwrap().parent(), Some(Package::root()));
+        assert_eq!(Package::root().parent(), None);
+        assert_eq!(package.child("lexadiky"), "io.github.lexadiky".parse().unwrap());
+    }
+
+    #[test]
+    fn subpackage() {
+        let package: Package = "io.github.lexadiky".parse().unwrap();
+        assert!(package.is_subpackage_of(&"io.github".parse().unwrap()));
+        assert!(package.is_subpackage_of(&Package::root()));
+        assert!(!package.is_subpackage_of(&package));
+        assert!(!package.is_subpackage_of(&"io.gitlab".parse().unwrap()));
+        assert!(!package.is_subpackage_of(&"io.github.lexadiky.model".parse().unwrap()));
+    }
+
+    #[test]
+    fn relativize_and_join() {
+        let package: Package = "io.github.lexadiky.model".parse().unwrap();
+        let relative = package.relativize(&"io.github".parse().unwrap()).unwrap();
+        assert_eq!(relative, "lexadiky.model".parse().unwrap());
+        assert_eq!(package.relativize(&package), Some(Package::root()));
+        assert_eq!(package.relativize(&"com.acme".parse().unwrap()), None);
+
+        let relocated = Package::from_str("com.acme.generated").unwrap().join(&relative);
+        assert_eq!(relocated.to_string(), "com.acme.generated.lexadiky.model");
+    }
+
+    #[test]
+    fn common_prefix() {
+        let left: Package = "io.github.lexadiky.model".parse().unwrap();
+        let right: Package = "io.github.other".parse().unwrap();
+        assert_eq!(left.common_prefix(&right), "io.github".parse().unwrap());
+        assert_eq!(left.common_prefix(&"com.acme".parse().unwrap()), Package::root());
+    }
+
+    #[test]
+    fn display() {
+        let package: Package = "io.github.lexadiky".parse().unwrap();
+        assert_eq!(format!("{}", package), "io.github.lexadiky");
+    }
+
     #[test]
     #[cfg(feature = "experimental")]
     fn test_path_conversion() {
@@ -1,19 +1,39 @@
 use crate::io::RenderKotlin;
-use crate::spec::{Annotation, CodeBlock, Name, Type};
+use crate::spec::{Annotation, CodeBlock, KDoc, Name, Type};
 use crate::spec::annotation::{mixin_annotation_mutators, AnnotationSlot};
+use crate::spec::kdoc::{KdocSlot, mixin_kdoc_mutators};
 use crate::tokens;
 
+/// Parameter of function or constructor.
+///
+/// KDoc of parameter is not rendered in place, it is merged into owner's KDoc as `@param` tag.
 #[derive(Debug, Clone)]
 pub struct Parameter {
     name: Name,
     parameter_type: Type,
     default_value: Option<CodeBlock>,
+    is_vararg: bool,
+    is_noinline: bool,
+    is_crossinline: bool,
     annotation_slot: AnnotationSlot,
+    kdoc: KdocSlot,
 }
 
 impl RenderKotlin for Parameter {
     fn render_into(&self, block: &mut CodeBlock) {
         block.push_renderable(&self.annotation_slot);
+        if self.is_noinline {
+            block.push_static_atom(tokens::keyword::NOINLINE);
+            block.push_space();
+        }
+        if self.is_crossinline {
+            block.push_static_atom(tokens::keyword::CROSSINLINE);
+            block.push_space();
+        }
+        if self.is_vararg {
+            block.push_static_atom(tokens::keyword::VARARG);
+            block.push_space();
+        }
         block.push_renderable(&self.name);
         block.push_static_atom(tokens::COLON);
         block.push_space();
@@ -33,7 +53,11 @@ impl Parameter {
             name: name.into(),
             parameter_type: parameter_type.into(),
             default_value: None,
+            is_vararg: false,
+            is_noinline: false,
+            is_crossinline: false,
             annotation_slot: AnnotationSlot::horizontal(),
+            kdoc: KdocSlot::default(),
         }
     }
 
@@ -42,14 +66,67 @@ impl Parameter {
         self
     }
 
+    /// Marks parameter as `vararg`, [Parameter::new] accepts element type in this case
+    pub fn vararg(mut self, flag: bool) -> Parameter {
+        self.is_vararg = flag;
+        self
+    }
+
+    /// Marks lambda parameter of inline function as `noinline`.
+    /// Exclusive with [Parameter::crossinline], the last call wins: `true` here resets `crossinline`.
+    pub fn noinline(mut self, flag: bool) -> Parameter {
+        self.is_noinline = flag;
+        if flag {
+            self.is_crossinline = false;
+        }
+        self
+    }
+
+    /// Marks lambda parameter of inline function as `crossinline`.
+    /// Exclusive with [Parameter::noinline], the last call wins: `true` here resets `noinline`.
+    pub fn crossinline(mut self, flag: bool) -> Parameter {
+        self.is_crossinline = flag;
+        if flag {
+            self.is_noinline = false;
+        }
+        self
+    }
+
+    /// `@param` tag for owner's KDoc, [None] if parameter is not documented
+    pub(crate) fn kdoc_tag(&self) -> Option<KDoc> {
+        self.kdoc.content().map(|content| {
+            KDoc::new()
+                .append("@param ")
+                .append(self.name.render_string().as_str())
+                .append(" ")
+                .append(content)
+        })
+    }
+
+    /// Checks if parameter is declared as `vararg`
+    pub(crate) fn is_vararg(&self) -> bool {
+        self.is_vararg
+    }
+
+    /// Checks if parameter has default value
+    pub(crate) fn has_default_value(&self) -> bool {
+        self.default_value.is_some()
+    }
+
+    /// Type of the parameter
+    pub(crate) fn parameter_type(&self) -> &Type {
+        &self.parameter_type
+    }
+
     mixin_annotation_mutators!();
+    mixin_kdoc_mutators!();
 }
 
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
     use crate::io::RenderKotlin;
-    use crate::spec::{Annotation, ClassLikeTypeName, CodeBlock, Name, Parameter, Type};
+    use crate::spec::{Annotation, AnnotationTarget, ClassLikeTypeName, CodeBlock, FunctionType, Name, Parameter, Type};
 
     #[test]
     fn test_rendering() {
@@ -77,6 +154,65 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_rendering_vararg() {
+        let parameter = Parameter::new("names", Type::string())
+            .vararg(true);
+
+        assert_eq!(
+            "vararg names: kotlin.String",
+            parameter.render_string()
+        )
+    }
+
+    #[test]
+    fn test_rendering_inline_modifiers() {
+        let parameter = Parameter::new("block", FunctionType::new(Type::unit()))
+            .noinline(true)
+            .crossinline(true);
+
+        assert_eq!(
+            "crossinline block: () -> kotlin.Unit",
+            parameter.render_string()
+        );
+
+        let parameter = Parameter::new("blocks", FunctionType::new(Type::unit()))
+            .crossinline(true)
+            .noinline(true)
+            .vararg(true);
+
+        assert_eq!(
+            "noinline vararg blocks: () -> kotlin.Unit",
+            parameter.render_string()
+        );
+    }
+
+    #[test]
+    fn test_rendering_with_targeted_annotation() {
+        let parameter = Parameter::new("id", Type::string())
+            .annotation(
+                Annotation::new(ClassLikeTypeName::from_str("com.squareup.moshi.Json").unwrap())
+                    .target(AnnotationTarget::Field)
+            );
+
+        assert_eq!(
+            "@field:com.squareup.moshi.Json() id: kotlin.String",
+            parameter.render_string()
+        )
+    }
+
+    #[test]
+    fn test_kdoc_is_not_rendered_in_place() {
+        let parameter = Parameter::new("id", Type::string())
+            .kdoc("User identifier");
+
+        assert_eq!("id: kotlin.String", parameter.render_string());
+        assert_eq!(
+            "/**\n * @param id User identifier\n */",
+            parameter.kdoc_tag().unwrap().render_string()
+        );
+    }
+
     #[test]
     fn test_rendering_with_annotation() {
         let parameter = Parameter::new(
@@ -1,5 +1,5 @@
 use crate::io::RenderKotlin;
-use crate::spec::{VisibilityModifier, CodeBlock, Parameter, Property};
+use crate::spec::{VisibilityModifier, CodeBlock, KDoc, Parameter, Property, Type};
 use crate::tokens;
 
 #[derive(Debug, Clone)]
@@ -12,7 +12,7 @@ impl RenderKotlin for PrimaryConstructorParameter {
     fn render_into(&self, block: &mut CodeBlock) {
         match self {
             PrimaryConstructorParameter::PropertyParameter(property) =>
-                block.push_renderable(property),
+                property.render_constructor_property_into(block),
             PrimaryConstructorParameter::FunctionLike(param) =>
                 block.push_renderable(param)
         }
@@ -20,6 +20,8 @@ impl RenderKotlin for PrimaryConstructorParameter {
 }
 
 /// Defines [Class's primary constructor](https://kotlinlang.org/docs/classes.html#constructors)
+///
+/// KDoc of parameters and properties is merged into class KDoc as `@param` and `@property` tags.
 #[derive(Debug, Clone)]
 pub struct PrimaryConstructor {
     arguments: Vec<PrimaryConstructorParameter>,
@@ -48,6 +50,43 @@ impl PrimaryConstructor {
         self.visibility_modifier = visibility_modifier;
         self
     }
+
+    /// Types of all parameters, including properties, in order of declaration
+    pub(crate) fn parameter_types(&self) -> impl Iterator<Item = &Type> {
+        self.arguments.iter().map(|argument| match argument {
+            PrimaryConstructorParameter::PropertyParameter(property) => property.property_type(),
+            PrimaryConstructorParameter::FunctionLike(parameter) => parameter.parameter_type(),
+        })
+    }
+
+    /// Properties declared in primary constructor
+    pub(crate) fn properties(&self) -> impl Iterator<Item = &Property> {
+        self.arguments.iter().filter_map(|argument| match argument {
+            PrimaryConstructorParameter::PropertyParameter(property) => Some(property),
+            PrimaryConstructorParameter::FunctionLike(_) => None,
+        })
+    }
+
+    /// `@param` and `@property` tags of documented parameters for class KDoc, in order of declaration
+    pub(crate) fn kdoc_tags(&self) -> impl Iterator<Item = KDoc> + '_ {
+        self.arguments.iter().filter_map(|argument| match argument {
+            PrimaryConstructorParameter::PropertyParameter(property) => property.kdoc_tag(),
+            PrimaryConstructorParameter::FunctionLike(parameter) => parameter.kdoc_tag(),
+        })
+    }
+
+    /// Checks if there are parameters which are not properties
+    pub(crate) fn has_plain_parameters(&self) -> bool {
+        self.arguments.iter().any(|argument| matches!(argument, PrimaryConstructorParameter::FunctionLike(_)))
+    }
+
+    /// Returns the only parameter if it is a property, [None] otherwise
+    pub(crate) fn single_property(&self) -> Option<&Property> {
+        match self.arguments.as_slice() {
+            [PrimaryConstructorParameter::PropertyParameter(property)] => Some(property),
+            _ => None,
+        }
+    }
 }
 
 impl RenderKotlin for PrimaryConstructor {
@@ -1,8 +1,11 @@
 use crate::io::RenderKotlin;
-use crate::spec::{VisibilityModifier, CodeBlock, MemberInheritanceModifier, Name, Type, Annotation};
+use crate::spec::{VisibilityModifier, CodeBlock, ContextParameter, KDoc, MemberInheritanceModifier, Name, Type, Annotation};
+use crate::spec::code_block::CodeBlockNode;
+use crate::spec::function_body::FunctionBody;
 use crate::spec::annotation::{mixin_annotation_mutators, AnnotationSlot};
 use crate::spec::kdoc::{KdocSlot, mixin_kdoc_mutators};
 use crate::tokens;
+use crate::util::ValidationError;
 
 #[derive(Debug, Clone)]
 enum PropertyInitializer {
@@ -34,6 +37,7 @@ impl RenderKotlin for PropertyInitializer {
 pub struct Property {
     name: Name,
     returns: Type,
+    context_parameters: Vec<ContextParameter>,
     inheritance_modifier: MemberInheritanceModifier,
     visibility_modifier: VisibilityModifier,
     initializer: Option<PropertyInitializer>,
@@ -48,14 +52,22 @@ pub struct Property {
 
 #[derive(Debug, Clone)]
 pub struct PropertyGetter {
-    code: CodeBlock,
+    body: FunctionBody,
     annotation_slot: AnnotationSlot
 }
 
 impl PropertyGetter {
     pub fn new<CodeBlockLike: Into<CodeBlock>>(code: CodeBlockLike) -> PropertyGetter {
         PropertyGetter {
-            code: code.into(),
+            body: FunctionBody::Block(code.into()),
+            annotation_slot: AnnotationSlot::vertical()
+        }
+    }
+
+    /// Creates getter with expression body, e.g. `get() = field.trim()`
+    pub fn expression<CodeBlockLike: Into<CodeBlock>>(expression: CodeBlockLike) -> PropertyGetter {
+        PropertyGetter {
+            body: FunctionBody::expression(expression),
             annotation_slot: AnnotationSlot::vertical()
         }
     }
@@ -69,19 +81,14 @@ impl RenderKotlin for PropertyGetter {
         block.push_static_atom(tokens::keyword::GET);
         block.push_round_brackets(|_| {});
         block.push_space();
-        block.push_static_atom(tokens::CURLY_BRACKET_LEFT);
-        block.push_new_line();
-        block.push_indent();
-        block.push_renderable(&self.code);
-        block.push_unindent();
-        block.push_static_atom(tokens::CURLY_BRACKET_RIGHT);
+        block.push_renderable(&self.body);
         block.push_new_line();
     }
 }
 
 #[derive(Debug, Clone)]
 pub struct PropertySetter {
-    code: CodeBlock,
+    body: FunctionBody,
     visibility_modifier: VisibilityModifier,
     annotation_slot: AnnotationSlot
 }
@@ -89,7 +96,16 @@ pub struct PropertySetter {
 impl PropertySetter {
     pub fn new<CodeBlockLike: Into<CodeBlock>>(code: CodeBlockLike) -> PropertySetter {
         PropertySetter {
-            code: code.into(),
+            body: FunctionBody::Block(code.into()),
+            visibility_modifier: VisibilityModifier::default(),
+            annotation_slot: AnnotationSlot::vertical()
+        }
+    }
+
+    /// Creates setter with expression body, e.g. `set(value) = println(value)`
+    pub fn expression<CodeBlockLike: Into<CodeBlock>>(expression: CodeBlockLike) -> PropertySetter {
+        PropertySetter {
+            body: FunctionBody::expression(expression),
             visibility_modifier: VisibilityModifier::default(),
             annotation_slot: AnnotationSlot::vertical()
         }
@@ -111,9 +127,7 @@ impl RenderKotlin for PropertySetter {
             parameters_code.push_static_atom(tokens::CONV_VAR_VALUE);
         });
         block.push_space();
-        block.push_curly_brackets(|set_body| {
-            set_body.push_renderable(&self.code);
-        });
+        block.push_renderable(&self.body);
         block.push_new_line();
     }
 }
@@ -123,6 +137,7 @@ impl Property {
         Property {
             name: name.into(),
             returns: returns.into(),
+            context_parameters: Vec::new(),
             inheritance_modifier: MemberInheritanceModifier::Final,
             visibility_modifier: VisibilityModifier::default(),
             initializer: None,
@@ -148,6 +163,13 @@ impl Property {
         self
     }
 
+    /// Adds [ContextParameter] to the property's `context(...)` clause.
+    /// Multiple context parameters can be added, they will appear in order this method is called.
+    pub fn context_parameter(mut self, context_parameter: ContextParameter) -> Property {
+        self.context_parameters.push(context_parameter);
+        self
+    }
+
     /// Sets [MemberInheritanceModifier]
     pub fn inheritance_modifier(mut self, inheritance_modifier: MemberInheritanceModifier) -> Property {
         self.inheritance_modifier = inheritance_modifier;
@@ -187,6 +209,42 @@ impl Property {
         self
     }
 
+    /// Checks that the property declaration is consistent, e.g. context receivers are not mixed with named context parameters
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        ContextParameter::validate_clause(&self.context_parameters)
+    }
+
+    /// Name of the declaration
+    pub(crate) fn name(&self) -> &Name {
+        &self.name
+    }
+
+    /// Type of the property
+    pub(crate) fn property_type(&self) -> &Type {
+        &self.returns
+    }
+
+    /// Checks if property is declared as `var`
+    pub(crate) fn is_mutable(&self) -> bool {
+        self.is_mutable
+    }
+
+    /// `@property` tag for class KDoc, [None] if property is not documented
+    pub(crate) fn kdoc_tag(&self) -> Option<KDoc> {
+        self.kdoc.content().map(|content| {
+            KDoc::new()
+                .append("@property ")
+                .append(self.name.render_string().as_str())
+                .append(" ")
+                .append(content)
+        })
+    }
+
+    /// Renders property declared in primary constructor, its KDoc is merged into class KDoc instead
+    pub(crate) fn render_constructor_property_into(&self, block: &mut CodeBlock) {
+        self.render_with(block, false);
+    }
+
     /// Adds `const` keyword to property
     pub fn constant(mut self, flag: bool) -> Property {
         self.is_const = flag;
@@ -199,9 +257,22 @@ impl Property {
 
 impl RenderKotlin for Property {
     fn render_into(&self, block: &mut CodeBlock) {
-        block.push_renderable(&self.kdoc);
+        self.render_with(block, true);
+    }
+}
+
+impl Property {
+    fn render_with(&self, block: &mut CodeBlock, include_kdoc: bool) {
+        if include_kdoc {
+            block.push_renderable(&self.kdoc);
+        }
         block.push_renderable(&self.annotation_slot);
 
+        if !self.context_parameters.is_empty() {
+            block.push_renderable(&ContextParameter::render_clause(&self.context_parameters));
+            block.push_new_line();
+        }
+
         block.push_renderable(&self.visibility_modifier);
         block.push_space();
         block.push_renderable(&self.inheritance_modifier);
@@ -232,6 +303,10 @@ impl RenderKotlin for Property {
         if let Some(initializer) = &self.initializer {
             block.push_renderable(initializer);
         }
+        let has_accessors = self.getter.is_some() || self.setter.is_some();
+        if has_accessors && !matches!(block.nodes.last(), Some(CodeBlockNode::NewLine)) {
+            block.push_new_line();
+        }
         if let Some(setter) = &self.setter {
             block.push_renderable(setter);
         }
@@ -270,6 +345,26 @@ mod tests {
         assert_eq!(rendered, expected);
     }
 
+    #[test]
+    fn test_context_parameters() {
+        let property = Property::new(Name::from("name"), Type::string())
+            .context_parameter(ContextParameter::receiver(Type::string()))
+            .delegate(CodeBlock::atom("lazy { this }"));
+
+        assert_eq!(
+            "context(kotlin.String)\npublic final val name: kotlin.String by lazy { this }",
+            property.render_string()
+        )
+    }
+
+    #[test]
+    fn test_mixed_context_parameters_validation() {
+        let property = Property::new(Name::from("name"), Type::string())
+            .context_parameter(ContextParameter::named("text", Type::string()));
+        assert!(property.clone().validate().is_ok());
+        assert!(property.context_parameter(ContextParameter::receiver(Type::int())).validate().is_err());
+    }
+
     #[test]
     fn test_constant() {
         let property = Property::new(Name::from("name"), Type::string())
@@ -350,6 +445,30 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_expression_accessors() {
+        let property = Property::new(Name::from("name"), Type::string())
+            .initializer(CodeBlock::atom("\"\""))
+            .getter(PropertyGetter::expression(CodeBlock::statement("field.trim()")))
+            .setter(PropertySetter::expression(CodeBlock::atom("println(value)")));
+
+        assert_eq!(
+            "public final var name: kotlin.String = \"\"\n    set(value) = println(value)\n    get() = field.trim()",
+            property.render_string()
+        )
+    }
+
+    #[test]
+    fn test_getter_without_initializer() {
+        let property = Property::new(Name::from("name"), Type::string())
+            .getter(PropertyGetter::expression(CodeBlock::atom("\"Alex\"")));
+
+        assert_eq!(
+            "public final val name: kotlin.String\n    get() = \"Alex\"",
+            property.render_string()
+        )
+    }
+
     #[test]
     fn test_getter_with_annotation() {
         let setter = PropertyGetter::new(CodeBlock::statement("println(47)"))
@@ -1,5 +1,5 @@
 use crate::io::RenderKotlin;
-use crate::spec::{VisibilityModifier, Argument, CodeBlock, Parameter, PrimaryConstructor};
+use crate::spec::{VisibilityModifier, Argument, CodeBlock, Parameter, PrimaryConstructor, Type};
 use crate::spec::kdoc::{KdocSlot, mixin_kdoc_mutators};
 use crate::tokens;
 
@@ -44,12 +44,17 @@ impl SecondaryConstructor {
         self
     }
 
+    /// Types of parameters in order of declaration
+    pub(crate) fn parameter_types(&self) -> impl Iterator<Item = &Type> {
+        self.parameters.iter().map(Parameter::parameter_type)
+    }
+
     mixin_kdoc_mutators!();
 }
 
 impl RenderKotlin for SecondaryConstructor {
     fn render_into(&self, block: &mut CodeBlock) {
-        block.push_renderable(&self.kdoc);
+        block.push_renderable(&self.kdoc.with_parameter_tags(&self.parameters));
 
         let mut pc = PrimaryConstructor::new()
             .visibility_modifier(self.visibility_modifier.clone());
@@ -0,0 +1,133 @@
+use crate::io::RenderKotlin;
+use crate::spec::{Argument, ClassLikeType, ClassLikeTypeName, CodeBlock, Type};
+use crate::tokens;
+
+/// Entry of class supertypes list, see [crate::spec::Class::inherits].
+///
+/// Any [Type] converts into [Supertype::Interface], so plain types can be passed wherever [Supertype] is expected.
+///
+/// # Examples
+/// ```rust
+/// use kotlin_poet_rs::io::RenderKotlin;
+/// use kotlin_poet_rs::spec::{Argument, ClassLikeTypeName, CodeBlock, Supertype};
+///
+/// let base = Supertype::class(
+///     ClassLikeTypeName::from("a.Base"),
+///     vec![Argument::new_positional(CodeBlock::atom("1"))],
+/// );
+/// let repository = Supertype::delegated(ClassLikeTypeName::from("a.Repository"), CodeBlock::atom("delegate"));
+///
+/// assert_eq!(base.render_string(), "a.Base(1)");
+/// assert_eq!(repository.render_string(), "a.Repository by delegate");
+/// ```
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub enum Supertype {
+    /// Interface or superclass without constructor call, e.g. `Comparable<T>`
+    Interface(Type),
+    /// Superclass with constructor call, e.g. `Base(arg1, arg2)`
+    Class {
+        supertype: Type,
+        arguments: Vec<Argument>,
+    },
+    /// Interface implemented by delegation, e.g. `Repository by delegate`
+    Delegated {
+        supertype: Type,
+        delegate: CodeBlock,
+    },
+}
+
+impl Supertype {
+    /// Creates supertype without constructor call
+    pub fn interface<TypeLike: Into<Type>>(supertype: TypeLike) -> Supertype {
+        Supertype::Interface(supertype.into())
+    }
+
+    /// Creates superclass constructor call, parentheses are rendered even if there are no arguments
+    pub fn class<TypeLike: Into<Type>>(supertype: TypeLike, arguments: Vec<Argument>) -> Supertype {
+        Supertype::Class {
+            supertype: supertype.into(),
+            arguments,
+        }
+    }
+
+    /// Creates interface delegated to [delegate] expression
+    pub fn delegated<TypeLike: Into<Type>, CodeBlockLike: Into<CodeBlock>>(
+        supertype: TypeLike,
+        delegate: CodeBlockLike,
+    ) -> Supertype {
+        Supertype::Delegated {
+            supertype: supertype.into(),
+            delegate: delegate.into(),
+        }
+    }
+}
+
+impl From<Type> for Supertype {
+    fn from(value: Type) -> Self {
+        Supertype::Interface(value)
+    }
+}
+
+impl From<ClassLikeType> for Supertype {
+    fn from(value: ClassLikeType) -> Self {
+        Supertype::interface(value)
+    }
+}
+
+impl From<ClassLikeTypeName> for Supertype {
+    fn from(value: ClassLikeTypeName) -> Self {
+        Supertype::interface(value)
+    }
+}
+
+impl RenderKotlin for Supertype {
+    fn render_into(&self, block: &mut CodeBlock) {
+        match self {
+            Supertype::Interface(supertype) => block.push_renderable(supertype),
+            Supertype::Class { supertype, arguments } => {
+                block.push_renderable(supertype);
+                block.push_round_brackets(|arguments_code| {
+                    arguments_code.push_comma_separated(arguments);
+                });
+            }
+            Supertype::Delegated { supertype, delegate } => {
+                block.push_renderable(supertype);
+                block.push_space();
+                block.push_static_atom(tokens::keyword::BY);
+                block.push_space();
+                block.push_renderable(delegate);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::RenderKotlin;
+    use crate::spec::{Argument, CodeBlock, Supertype, Type};
+
+    #[test]
+    fn render_interface() {
+        assert_eq!(Supertype::from(Type::comparable(Type::int())).render_string(), "kotlin.Comparable<kotlin.Int>");
+    }
+
+    #[test]
+    fn render_class_without_arguments() {
+        assert_eq!(Supertype::class(Type::any(), vec![]).render_string(), "kotlin.Any()");
+    }
+
+    #[test]
+    fn render_class_with_arguments() {
+        let supertype = Supertype::class(Type::any(), vec![
+            Argument::new_positional(CodeBlock::atom("1")),
+            Argument::new_named("name", CodeBlock::atom("\"a\"")),
+        ]);
+        assert_eq!(supertype.render_string(), "kotlin.Any(1, name = \"a\")");
+    }
+
+    #[test]
+    fn render_delegated() {
+        let supertype = Supertype::delegated(Type::list(Type::int()), CodeBlock::atom("items"));
+        assert_eq!(supertype.render_string(), "kotlin.collections.List<kotlin.Int> by items");
+    }
+}
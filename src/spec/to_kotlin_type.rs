@@ -0,0 +1,194 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use crate::spec::Type;
+
+/// Maps Rust type to the closest Kotlin [Type].
+///
+/// Implemented for primitives, [String], [Option] (as nullable type), standard collections, tuples (as `Pair` and `Triple`)
+/// and arrays, so generic code can map Rust type parameters into Kotlin types.
+/// Arrays and slices of primitives are mapped to dedicated Kotlin array types (e.g. `[i32; 4]` to `kotlin.IntArray`).
+/// Note that `isize` and `usize` are mapped to `kotlin.Long` and `kotlin.ULong`.
+///
+/// # Examples
+/// ```rust
+/// use std::collections::HashMap;
+/// use kotlin_poet_rs::io::RenderKotlin;
+/// use kotlin_poet_rs::spec::ToKotlinType;
+///
+/// assert_eq!(
+///     HashMap::<String, Vec<Option<u32>>>::to_kotlin_type().render_string(),
+///     "kotlin.collections.Map<kotlin.String, kotlin.collections.List<kotlin.UInt?>>"
+/// );
+/// ```
+pub trait ToKotlinType {
+    /// Creates Kotlin [Type] corresponding to `Self`
+    fn to_kotlin_type() -> Type;
+
+    /// Creates Kotlin [Type] of array with `Self` elements, `kotlin.Array<Self>` unless there is a dedicated array type
+    fn to_kotlin_array_type() -> Type where Self: Sized {
+        Type::array(Self::to_kotlin_type())
+    }
+}
+
+macro_rules! impl_to_kotlin_type {
+    ($($rust:ty => $factory:ident $(/ $array_factory:ident)?),+ $(,)?) => {
+        $(
+        impl ToKotlinType for $rust {
+            fn to_kotlin_type() -> Type {
+                Type::$factory()
+            }
+
+            $(
+            fn to_kotlin_array_type() -> Type {
+                Type::$array_factory()
+            }
+            )?
+        }
+        )+
+    };
+}
+
+impl_to_kotlin_type!(
+    i8 => byte / byte_array,
+    i16 => short / short_array,
+    i32 => int / int_array,
+    i64 => long / long_array,
+    isize => long / long_array,
+    u8 => ubyte / ubyte_array,
+    u16 => ushort / ushort_array,
+    u32 => uint / uint_array,
+    u64 => ulong / ulong_array,
+    usize => ulong / ulong_array,
+    f32 => float / float_array,
+    f64 => double / double_array,
+    bool => boolean / boolean_array,
+    char => char / char_array,
+    str => string,
+    String => string,
+    () => unit,
+);
+
+impl<T: ToKotlinType + ?Sized> ToKotlinType for &T {
+    fn to_kotlin_type() -> Type {
+        T::to_kotlin_type()
+    }
+}
+
+impl<T: ToKotlinType + ?Sized> ToKotlinType for Box<T> {
+    fn to_kotlin_type() -> Type {
+        T::to_kotlin_type()
+    }
+}
+
+impl<T: ToKotlinType> ToKotlinType for Option<T> {
+    fn to_kotlin_type() -> Type {
+        T::to_kotlin_type().nullable(true)
+    }
+}
+
+impl<T: ToKotlinType> ToKotlinType for Vec<T> {
+    fn to_kotlin_type() -> Type {
+        Type::list(T::to_kotlin_type())
+    }
+}
+
+impl<T: ToKotlinType> ToKotlinType for [T] {
+    fn to_kotlin_type() -> Type {
+        T::to_kotlin_array_type()
+    }
+}
+
+impl<T: ToKotlinType, const N: usize> ToKotlinType for [T; N] {
+    fn to_kotlin_type() -> Type {
+        T::to_kotlin_array_type()
+    }
+}
+
+impl<T: ToKotlinType, S> ToKotlinType for HashSet<T, S> {
+    fn to_kotlin_type() -> Type {
+        Type::set(T::to_kotlin_type())
+    }
+}
+
+impl<T: ToKotlinType> ToKotlinType for BTreeSet<T> {
+    fn to_kotlin_type() -> Type {
+        Type::set(T::to_kotlin_type())
+    }
+}
+
+impl<K: ToKotlinType, V: ToKotlinType, S> ToKotlinType for HashMap<K, V, S> {
+    fn to_kotlin_type() -> Type {
+        Type::map(K::to_kotlin_type(), V::to_kotlin_type())
+    }
+}
+
+impl<K: ToKotlinType, V: ToKotlinType> ToKotlinType for BTreeMap<K, V> {
+    fn to_kotlin_type() -> Type {
+        Type::map(K::to_kotlin_type(), V::to_kotlin_type())
+    }
+}
+
+impl<A: ToKotlinType, B: ToKotlinType> ToKotlinType for (A, B) {
+    fn to_kotlin_type() -> Type {
+        Type::pair(A::to_kotlin_type(), B::to_kotlin_type())
+    }
+}
+
+impl<A: ToKotlinType, B: ToKotlinType, C: ToKotlinType> ToKotlinType for (A, B, C) {
+    fn to_kotlin_type() -> Type {
+        Type::triple(A::to_kotlin_type(), B::to_kotlin_type(), C::to_kotlin_type())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeSet, HashMap};
+
+    use crate::io::RenderKotlin;
+    use crate::spec::{ToKotlinType, Type};
+
+    #[test]
+    fn map_primitives() {
+        assert_eq!(i32::to_kotlin_type(), Type::int());
+        assert_eq!(u64::to_kotlin_type(), Type::ulong());
+        assert_eq!(bool::to_kotlin_type(), Type::boolean());
+        assert_eq!(String::to_kotlin_type(), Type::string());
+        assert_eq!(<&str>::to_kotlin_type(), Type::string());
+        assert_eq!(<()>::to_kotlin_type(), Type::unit());
+    }
+
+    #[test]
+    fn map_option_to_nullable() {
+        assert_eq!(Option::<f64>::to_kotlin_type(), Type::double().nullable(true));
+        assert_eq!(Option::<Option<f64>>::to_kotlin_type(), Type::double().nullable(true));
+    }
+
+    #[test]
+    fn map_collections() {
+        assert_eq!(Vec::<String>::to_kotlin_type(), Type::list(Type::string()));
+        assert_eq!(BTreeSet::<char>::to_kotlin_type(), Type::set(Type::char()));
+        assert_eq!(
+            HashMap::<String, i64>::to_kotlin_type(),
+            Type::map(Type::string(), Type::long())
+        );
+    }
+
+    #[test]
+    fn map_arrays() {
+        assert_eq!(<[u8; 4]>::to_kotlin_type(), Type::ubyte_array());
+        assert_eq!(<[i32; 2]>::to_kotlin_type(), Type::int_array());
+        assert_eq!(<[bool]>::to_kotlin_type(), Type::boolean_array());
+        assert_eq!(<[String; 2]>::to_kotlin_type(), Type::array(Type::string()));
+        assert_eq!(<[Option<i32>; 2]>::to_kotlin_type(), Type::array(Type::int().nullable(true)));
+        assert_eq!(Type::ubyte_array().render_string(), "kotlin.UByteArray");
+    }
+
+    #[test]
+    fn map_tuples() {
+        assert_eq!(<(i32, String)>::to_kotlin_type(), Type::pair(Type::int(), Type::string()));
+        assert_eq!(
+            <(i32, bool, Box<i16>)>::to_kotlin_type(),
+            Type::triple(Type::int(), Type::boolean(), Type::short())
+        );
+    }
+}
@@ -1,10 +1,13 @@
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
 use std::str::FromStr;
 
 use crate::io::RenderKotlin;
-use crate::spec::{ClassLikeTypeName, CodeBlock, FunctionType, Name, Package};
+use crate::spec::{AnnotatedType, Annotation, ClassLikeTypeName, CodeBlock, DefinitelyNonNullableType, FunctionType, Name, NullableType, TypeProjection};
 use crate::spec::class_like_type::ClassLikeType;
+use crate::spec::type_parser::TypeParser;
 use crate::tokens;
-use crate::util::{SemanticConversionError, yolo_from_str};
+use crate::util::{compare_rendered, SemanticConversionError, yolo_from_str};
 
 // region stdlib types codegen
 macro_rules! fn_basic_type_factory {
@@ -15,15 +18,15 @@ macro_rules! fn_basic_type_factory {
             ".",
             stringify!($class)
         )]
-        pub fn $identifier() -> Type {
+        pub fn $identifier() -> $crate::spec::Type {
             use std::str::FromStr;
 
-            let package = Package::from_str(stringify!($($package).+)).unwrap();
-            let name = Name::from_str(stringify!($class)).unwrap();
+            let package = $crate::spec::Package::from_str(stringify!($($package).+)).unwrap();
+            let name = $crate::spec::Name::from_str(stringify!($class)).unwrap();
 
-            Type::ClassLike(
-                ClassLikeType::new(
-                    ClassLikeTypeName::top_level(
+            $crate::spec::Type::ClassLike(
+                $crate::spec::ClassLikeType::new(
+                    $crate::spec::ClassLikeTypeName::top_level(
                         package,
                         name
                     )
@@ -41,14 +44,14 @@ macro_rules! fn_generic_type_factory {
             ".",
             stringify!($class)
         )]
-        pub fn $identifier($($generic: Type,)+) -> Type {
+        pub fn $identifier($($generic: impl Into<$crate::spec::TypeProjection>,)+) -> $crate::spec::Type {
             use std::str::FromStr;
 
-            let package = Package::from_str(stringify!($($package).+)).unwrap();
-            let name = Name::from_str(stringify!($class)).unwrap();
+            let package = $crate::spec::Package::from_str(stringify!($($package).+)).unwrap();
+            let name = $crate::spec::Name::from_str(stringify!($class)).unwrap();
 
-            let mut inner_type = ClassLikeType::new(
-                ClassLikeTypeName::top_level(
+            let mut inner_type = $crate::spec::ClassLikeType::new(
+                $crate::spec::ClassLikeTypeName::top_level(
                     package,
                     name,
                 )
@@ -58,16 +61,24 @@ macro_rules! fn_generic_type_factory {
             inner_type = inner_type.generic_argument($generic);
             )+
 
-            Type::ClassLike(
+            $crate::spec::Type::ClassLike(
                 inner_type
             )
         }
     };
 }
+// re-exported for catalogs only
+#[cfg(any(feature = "catalog-kotlinx-coroutines", feature = "catalog-kotlinx-serialization", feature = "catalog-java"))]
+pub(crate) use fn_basic_type_factory;
+#[cfg(any(feature = "catalog-kotlinx-coroutines", feature = "catalog-kotlinx-serialization"))]
+pub(crate) use fn_generic_type_factory;
 // endregion stdlib types codegen
 
 /// Kotlin fully resolved / qualified type
-#[derive(PartialEq, Debug, Clone)]
+///
+/// Types are ordered as they render, e.g. generic `T` goes before `kotlin.Int`.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+#[non_exhaustive]
 pub enum Type {
     /// Type that behaves like class (e.g. `kotlin.String`, `kotlin.collections.List<String>`)
     ClassLike(ClassLikeType),
@@ -75,6 +86,13 @@ pub enum Type {
     Function(FunctionType),
     /// Generic argument as type (e.g. `T`)
     Generic(Name),
+    /// Nullable version of inner type (e.g. `T?`, `((kotlin.Int) -> kotlin.Unit)?`), see [Type::nullable].
+    /// Nullable class like types are represented via [ClassLikeType::nullable] instead.
+    Nullable(NullableType),
+    /// Definitely non-nullable version of generic type (e.g. `T & kotlin.Any`), see [Type::definitely_non_nullable]
+    DefinitelyNonNullable(DefinitelyNonNullableType),
+    /// Inner type with type-use annotations rendered before it (e.g. `@Composable () -> kotlin.Unit`), see [Type::annotation]
+    Annotated(AnnotatedType),
 }
 
 impl Type {
@@ -83,15 +101,204 @@ impl Type {
         Type::Generic(name.into())
     }
 
+    /// Marks the type as nullable, works uniformly for every variant.
+    /// Nullable function types are automatically wrapped in parentheses (e.g. `(() -> kotlin.Unit)?`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use kotlin_poet_rs::io::RenderKotlin;
+    /// use kotlin_poet_rs::spec::{FunctionType, Type};
+    ///
+    /// assert_eq!(Type::generic("T").nullable(true).render_string(), "T?");
+    /// assert_eq!(
+    ///     Type::Function(FunctionType::new(Type::unit())).nullable(true).render_string(),
+    ///     "(() -> kotlin.Unit)?"
+    /// );
+    /// ```
+    pub fn nullable(self, flag: bool) -> Type {
+        match (self, flag) {
+            (Type::ClassLike(class_like), flag) => Type::ClassLike(class_like.nullable(flag)),
+            // nullable definitely non-nullable type collapses back to `T?`, so it needs no parentheses either
+            (Type::Annotated(annotated), flag) if !annotated.inner().requires_parentheses()
+                || (flag && matches!(annotated.inner(), Type::DefinitelyNonNullable(_))) => {
+                Type::Annotated(annotated.map_inner(|inner| inner.nullable(flag)))
+            }
+            (Type::Nullable(nullable), true) => Type::Nullable(nullable),
+            (Type::Nullable(nullable), false) => nullable.into_inner(),
+            (Type::DefinitelyNonNullable(non_nullable), true) => Type::Generic(non_nullable.generic().clone()).nullable(true),
+            (other, true) => Type::Nullable(NullableType::new(other)),
+            (other, false) => other,
+        }
+    }
+
+    /// Checks if the type is nullable
+    pub fn is_nullable(&self) -> bool {
+        match self {
+            Type::ClassLike(class_like) => class_like.is_nullable(),
+            Type::Nullable(_) => true,
+            Type::Annotated(annotated) => annotated.inner().is_nullable(),
+            _ => false,
+        }
+    }
+
+    /// Converts generic type into definitely non-nullable type `T & kotlin.Any`.
+    /// Other types are just made non-nullable as they are definitely non-nullable already.
+    pub fn definitely_non_nullable(self) -> Type {
+        match self.nullable(false) {
+            Type::Generic(name) => Type::DefinitelyNonNullable(DefinitelyNonNullableType::new(name)),
+            Type::Annotated(annotated) => Type::Annotated(annotated.map_inner(Type::definitely_non_nullable)),
+            other => other,
+        }
+    }
+
+    /// Adds type-use [Annotation] rendered before the type, works uniformly for every variant.
+    /// Multiple annotations will appear in order this method is called.
+    ///
+    /// Annotations of nullable function types are placed inside parentheses (e.g. `(@Composable () -> kotlin.Unit)?`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use kotlin_poet_rs::io::RenderKotlin;
+    /// use kotlin_poet_rs::spec::{Annotation, ClassLikeTypeName, FunctionType, Type};
+    ///
+    /// let composable = Annotation::new(ClassLikeTypeName::from("androidx.compose.runtime.Composable"));
+    /// let content = Type::Function(FunctionType::new(Type::unit()))
+    ///     .annotation(composable);
+    ///
+    /// assert_eq!(content.render_string(), "@androidx.compose.runtime.Composable () -> kotlin.Unit");
+    /// assert_eq!(
+    ///     content.nullable(true).render_string(),
+    ///     "(@androidx.compose.runtime.Composable () -> kotlin.Unit)?"
+    /// );
+    /// ```
+    pub fn annotation(self, annotation: Annotation) -> Type {
+        match self {
+            Type::Annotated(mut annotated) => {
+                annotated.push(annotation);
+                Type::Annotated(annotated)
+            }
+            Type::Nullable(nullable) if nullable.inner().requires_parentheses() => {
+                Type::Nullable(NullableType::new(nullable.into_inner().annotation(annotation)))
+            }
+            other => Type::Annotated(AnnotatedType::new(annotation, other))
+        }
+    }
+
+    /// Visits this type and every type nested in it (generic arguments, function type parts, nullable inner types, etc.)
+    /// in pre-order.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use kotlin_poet_rs::io::RenderKotlin;
+    /// use kotlin_poet_rs::spec::Type;
+    ///
+    /// let mut visited = Vec::new();
+    /// Type::map(Type::string(), Type::list(Type::int()))
+    ///     .walk(&mut |it| visited.push(it.render_string()));
+    ///
+    /// assert_eq!(visited, vec![
+    ///     "kotlin.collections.Map<kotlin.String, kotlin.collections.List<kotlin.Int>>",
+    ///     "kotlin.String",
+    ///     "kotlin.collections.List<kotlin.Int>",
+    ///     "kotlin.Int",
+    /// ]);
+    /// ```
+    pub fn walk<F: FnMut(&Type)>(&self, visitor: &mut F) {
+        visitor(self);
+        match self {
+            Type::ClassLike(class_like) => {
+                for projected_type in class_like.generic_arguments().iter().filter_map(TypeProjection::projected_type) {
+                    projected_type.walk(visitor);
+                }
+            }
+            Type::Function(function) => {
+                for context_receiver in function.context_receiver_types() {
+                    context_receiver.walk(visitor);
+                }
+                if let Some(receiver) = function.receiver_type() {
+                    receiver.walk(visitor);
+                }
+                for parameter in function.parameter_types() {
+                    parameter.walk(visitor);
+                }
+                function.return_type().walk(visitor);
+            }
+            Type::Generic(_) | Type::DefinitelyNonNullable(_) => {}
+            Type::Nullable(nullable) => nullable.inner().walk(visitor),
+            Type::Annotated(annotated) => annotated.inner().walk(visitor),
+        }
+    }
+
+    /// Collects names of all classes referenced by this type, including type-use annotations
+    pub fn referenced_class_names(&self) -> BTreeSet<ClassLikeTypeName> {
+        let mut names = BTreeSet::new();
+        self.walk(&mut |nested| match nested {
+            Type::ClassLike(class_like) => {
+                names.insert(class_like.type_name().clone());
+            }
+            Type::Annotated(annotated) => {
+                names.extend(annotated.annotations().iter().map(|annotation| annotation.type_name().clone()));
+            }
+            _ => {}
+        });
+        names
+    }
+
+    /// Replaces every occurrence of generic type [name] with [replacement], nullability of the occurrence is preserved.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use kotlin_poet_rs::io::RenderKotlin;
+    /// use kotlin_poet_rs::spec::{FunctionType, Name, Type};
+    ///
+    /// let mapper = Type::from(
+    ///     FunctionType::new(Type::list(Type::generic("T").nullable(true)))
+    ///         .parameter(Type::generic("T"))
+    /// );
+    ///
+    /// assert_eq!(
+    ///     mapper.substitute(&Name::from("T"), &Type::string()).render_string(),
+    ///     "(kotlin.String) -> kotlin.collections.List<kotlin.String?>"
+    /// );
+    /// ```
+    pub fn substitute(&self, name: &Name, replacement: &Type) -> Type {
+        let mut substitute = |nested: Type| nested.substitute(name, replacement);
+        match self {
+            Type::Generic(generic) if generic == name => replacement.clone(),
+            Type::Generic(_) => self.clone(),
+            Type::ClassLike(class_like) => Type::ClassLike(class_like.clone().map_types(&mut substitute)),
+            Type::Function(function) => Type::Function(function.clone().map_types(&mut substitute)),
+            Type::Nullable(nullable) => nullable.inner().substitute(name, replacement).nullable(true),
+            Type::DefinitelyNonNullable(non_nullable) => Type::Generic(non_nullable.generic().clone())
+                .substitute(name, replacement)
+                .definitely_non_nullable(),
+            Type::Annotated(annotated) => annotated.annotations().iter()
+                .cloned()
+                .fold(annotated.inner().substitute(name, replacement), Type::annotation),
+        }
+    }
+
+    /// Checks if type has to be wrapped into parentheses to be followed by `?` or `.`
+    pub(crate) fn requires_parentheses(&self) -> bool {
+        matches!(self, Type::Function(_) | Type::DefinitelyNonNullable(_) | Type::Annotated(_))
+    }
+
     // Integer numbers
     fn_basic_type_factory!(int, kotlin, Int);
     fn_basic_type_factory!(long, kotlin, Long);
     fn_basic_type_factory!(short, kotlin, Short);
     fn_basic_type_factory!(byte, kotlin, Byte);
 
+    // Unsigned integer numbers
+    fn_basic_type_factory!(uint, kotlin, UInt);
+    fn_basic_type_factory!(ulong, kotlin, ULong);
+    fn_basic_type_factory!(ushort, kotlin, UShort);
+    fn_basic_type_factory!(ubyte, kotlin, UByte);
+
     // Floating point numbers
     fn_basic_type_factory!(float, kotlin, Float);
     fn_basic_type_factory!(double, kotlin, Double);
+    fn_basic_type_factory!(number, kotlin, Number);
 
     // Logic
     fn_basic_type_factory!(boolean, kotlin, Boolean);
@@ -99,17 +306,51 @@ impl Type {
     // Text
     fn_basic_type_factory!(char, kotlin, Char);
     fn_basic_type_factory!(string, kotlin, String);
+    fn_basic_type_factory!(char_sequence, kotlin, CharSequence);
 
     // Control Types
     fn_basic_type_factory!(unit, kotlin, Unit);
     fn_basic_type_factory!(any, kotlin, Any);
     fn_basic_type_factory!(nothing, kotlin, Nothing);
+    fn_generic_type_factory!(comparable, kotlin, Comparable<value>);
+    fn_generic_type_factory!(lazy, kotlin, Lazy<value>);
+    fn_generic_type_factory!(result, kotlin, Result<value>);
+
+    // Errors
+    fn_basic_type_factory!(throwable, kotlin, Throwable);
+    fn_basic_type_factory!(exception, kotlin, Exception);
+
+    // Tuples
+    fn_generic_type_factory!(pair, kotlin, Pair<first, second>);
+    fn_generic_type_factory!(triple, kotlin, Triple<first, second, third>);
 
     // Collections
     fn_generic_type_factory!(map, kotlin.collections, Map<key, value>);
     fn_generic_type_factory!(list, kotlin.collections, List<value>);
     fn_generic_type_factory!(set, kotlin.collections, Set<value>);
+    fn_generic_type_factory!(mutable_map, kotlin.collections, MutableMap<key, value>);
+    fn_generic_type_factory!(mutable_list, kotlin.collections, MutableList<value>);
+    fn_generic_type_factory!(mutable_set, kotlin.collections, MutableSet<value>);
+    fn_generic_type_factory!(collection, kotlin.collections, Collection<value>);
+    fn_generic_type_factory!(mutable_collection, kotlin.collections, MutableCollection<value>);
+    fn_generic_type_factory!(iterable, kotlin.collections, Iterable<value>);
+    fn_generic_type_factory!(mutable_iterable, kotlin.collections, MutableIterable<value>);
+    fn_generic_type_factory!(sequence, kotlin.sequences, Sequence<value>);
+
+    // Arrays
     fn_generic_type_factory!(array, kotlin, Array<value>);
+    fn_basic_type_factory!(boolean_array, kotlin, BooleanArray);
+    fn_basic_type_factory!(char_array, kotlin, CharArray);
+    fn_basic_type_factory!(byte_array, kotlin, ByteArray);
+    fn_basic_type_factory!(short_array, kotlin, ShortArray);
+    fn_basic_type_factory!(int_array, kotlin, IntArray);
+    fn_basic_type_factory!(long_array, kotlin, LongArray);
+    fn_basic_type_factory!(float_array, kotlin, FloatArray);
+    fn_basic_type_factory!(double_array, kotlin, DoubleArray);
+    fn_basic_type_factory!(ubyte_array, kotlin, UByteArray);
+    fn_basic_type_factory!(ushort_array, kotlin, UShortArray);
+    fn_basic_type_factory!(uint_array, kotlin, UIntArray);
+    fn_basic_type_factory!(ulong_array, kotlin, ULongArray);
 }
 
 impl From<ClassLikeTypeName> for Type {
@@ -124,34 +365,57 @@ impl From<ClassLikeType> for Type {
     }
 }
 
+impl From<FunctionType> for Type {
+    fn from(value: FunctionType) -> Self {
+        Type::Function(value)
+    }
+}
+
 yolo_from_str!(Type);
+/// Parses [Type] from Kotlin type syntax, for example `suspend kotlin.String.(kotlin.Int) -> kotlin.Unit`.
+///
+/// Only type-parameter-shaped names (`T`, `K2`) are parsed as [Type::Generic],
+/// other undotted names (`String`, `Box<T>`) are parsed as class like types in root package.
+/// Errors contain position of the problem in parsed string, see [SemanticConversionError::position].
 impl FromStr for Type {
     type Err = SemanticConversionError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let clear = s.trim();
+        TypeParser::parse(s)
+    }
+}
 
-        if !clear.contains(tokens::DOT) {
-            return Ok(
-                Type::Generic(
-                    Name::from_str(clear)?
-                )
-            );
-        }
+impl Ord for Type {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare_rendered(self, other).then_with(|| match (self, other) {
+            (Type::ClassLike(left), Type::ClassLike(right)) => left.cmp(right),
+            (Type::Function(left), Type::Function(right)) => left.cmp(right),
+            (Type::Generic(left), Type::Generic(right)) => left.cmp(right),
+            (Type::Nullable(left), Type::Nullable(right)) => left.cmp(right),
+            (Type::DefinitelyNonNullable(left), Type::DefinitelyNonNullable(right)) => left.cmp(right),
+            (Type::Annotated(left), Type::Annotated(right)) => left.cmp(right),
+            _ => self.variant_index().cmp(&other.variant_index()),
+        })
+    }
+}
 
-        if clear.contains(tokens::ROUND_BRACKET_LEFT) {
-            return Err(
-                SemanticConversionError::new(
-                    "Function types are not supported by Type::from_str"
-                )
-            );
-        }
+impl PartialOrd for Type {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
 
-        Ok(
-            Type::ClassLike(
-                ClassLikeType::from_str(clear)?
-            )
-        )
+impl Type {
+    /// Index of the variant, breaks ties between types of different kinds rendering the same way, e.g. `T`
+    fn variant_index(&self) -> u8 {
+        match self {
+            Type::ClassLike(_) => 0,
+            Type::Function(_) => 1,
+            Type::Generic(_) => 2,
+            Type::Nullable(_) => 3,
+            Type::DefinitelyNonNullable(_) => 4,
+            Type::Annotated(_) => 5,
+        }
     }
 }
 
@@ -160,7 +424,29 @@ impl RenderKotlin for Type {
         match self {
             Type::ClassLike(class_like) => block.push_renderable(class_like),
             Type::Generic(name) => block.push_renderable(name),
-            Type::Function(lambda) => block.push_renderable(lambda)
+            Type::Function(lambda) => block.push_renderable(lambda),
+            Type::Nullable(nullable) => {
+                if nullable.inner().requires_parentheses() {
+                    block.push_round_brackets(|inner_code| inner_code.push_renderable(nullable.inner()));
+                } else {
+                    block.push_renderable(nullable.inner());
+                }
+                block.push_static_atom(tokens::QUESTION_MARK);
+            }
+            Type::DefinitelyNonNullable(non_nullable) => {
+                block.push_renderable(non_nullable.generic());
+                block.push_space();
+                block.push_static_atom(tokens::AMPERSAND);
+                block.push_space();
+                block.push_renderable(&Type::any());
+            }
+            Type::Annotated(annotated) => {
+                for annotation in annotated.annotations() {
+                    annotation.render_compact_into(block);
+                    block.push_space();
+                }
+                block.push_renderable(annotated.inner());
+            }
         }
     }
 }
@@ -170,7 +456,7 @@ mod tests {
     use std::str::FromStr;
 
     use crate::io::RenderKotlin;
-    use crate::spec::{Name, Type};
+    use crate::spec::{Annotation, Argument, ClassLikeType, ClassLikeTypeName, CodeBlock, FunctionType, Name, Package, Type, TypeProjection};
 
     #[test]
     fn render_generic_parameter() {
@@ -181,11 +467,277 @@ mod tests {
 
     #[test]
     fn parse_fn_type() {
-        let new_type = Type::from_str("() -> String");
-        assert!(matches!(
-            new_type,
-            Err(SemanticConversionError)
-        ));
+        let new_type = Type::from_str("() -> kotlin.String");
+        assert_eq!(
+            new_type.unwrap(),
+            Type::Function(FunctionType::new(Type::string()))
+        );
+    }
+
+    #[test]
+    fn parse_error_position() {
+        let new_type = Type::from_str("kotlin.collections.List<kotlin.String");
+        assert_eq!(new_type.unwrap_err().position(), Some(37));
+    }
+
+    #[test]
+    fn parse_nested_metadata_name() {
+        let new_type = Type::from_str("a/b/Outer.Inner");
+        assert_eq!(
+            new_type.unwrap(),
+            Type::from(ClassLikeTypeName::nested(
+                Package::from_str("a.b").unwrap(),
+                vec![Name::from("Outer"), Name::from("Inner")],
+            ))
+        );
+    }
+
+    #[test]
+    fn nullable_is_canonical() {
+        assert_eq!(Type::string().nullable(true), Type::from(ClassLikeType::new(ClassLikeTypeName::from("kotlin.String")).nullable(true)));
+        assert!(matches!(Type::string().nullable(true), Type::ClassLike(_)));
+        assert_eq!(Type::generic("T").nullable(true).nullable(true).render_string(), "T?");
+        assert_eq!(
+            Type::generic("T").definitely_non_nullable().nullable(true),
+            Type::generic("T").nullable(true)
+        );
+    }
+
+    #[test]
+    fn nullable_annotated_definitely_non_nullable() {
+        let annotation = Annotation::new(ClassLikeTypeName::from("a.A"));
+        let nullable = Type::generic("T").definitely_non_nullable().annotation(annotation.clone()).nullable(true);
+
+        assert_eq!(nullable, Type::generic("T").nullable(true).annotation(annotation));
+        assert_eq!(nullable.render_string(), "@a.A T?");
+    }
+
+    #[test]
+    fn sorted_set_follows_render() {
+        let set: std::collections::BTreeSet<Type> = [
+            Type::string(),
+            Type::list(Type::string()),
+            Type::generic("T"),
+            Type::int(),
+            Type::string(),
+        ].into_iter().collect();
+
+        assert_eq!(
+            set.iter().map(|it| it.render_string()).collect::<Vec<_>>(),
+            vec!["T", "kotlin.Int", "kotlin.String", "kotlin.collections.List<kotlin.String>"]
+        );
+    }
+
+    #[test]
+    fn sorting_matches_rendered_order() {
+        let mut types = vec![
+            Type::from_str("kotlin.String").unwrap(),
+            Type::generic("A"),
+            Type::from(ClassLikeTypeName::from("a.z")),
+            Type::from(ClassLikeTypeName::from_str("a/b/A").unwrap()),
+            Type::list(Type::generic("T")).nullable(true),
+            Type::from(FunctionType::new(Type::unit())),
+            Type::generic("T").definitely_non_nullable(),
+            Type::generic("T").nullable(true),
+            Type::string().annotation(Annotation::new(ClassLikeTypeName::from("a.A"))),
+        ];
+        let mut rendered = types.iter().map(|it| it.render_string()).collect::<Vec<_>>();
+
+        types.sort();
+        rendered.sort();
+
+        assert_eq!(types.iter().map(|it| it.render_string()).collect::<Vec<_>>(), rendered);
+    }
+
+    #[test]
+    fn ordering_is_consistent_with_equality() {
+        let generic = Type::generic("C");
+        let class_like = Type::from(ClassLikeTypeName::top_level(crate::spec::Package::root(), "C"));
+
+        assert_eq!(generic.render_string(), class_like.render_string());
+        assert_ne!(generic.cmp(&class_like), std::cmp::Ordering::Equal);
+        assert_eq!(generic.cmp(&generic.clone()), std::cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn referenced_class_names() {
+        let function = Type::from(
+            FunctionType::new(Type::list(TypeProjection::star()))
+                .receiver(Type::generic("T"))
+                .parameter(Type::map(Type::string(), Type::int().nullable(true)))
+        ).annotation(Annotation::new(ClassLikeTypeName::from("a.Composable")));
+
+        assert_eq!(
+            function.referenced_class_names().iter().map(|it| it.render_string()).collect::<Vec<_>>(),
+            vec![
+                "a.Composable",
+                "kotlin.Int",
+                "kotlin.String",
+                "kotlin.collections.List",
+                "kotlin.collections.Map",
+            ]
+        );
+    }
+
+    #[test]
+    fn substitute_generic() {
+        let source = Type::from_str(
+            "context(T) T.(kotlin.collections.Map<in T, R>, T & Any) -> @a.A T?"
+        ).unwrap();
+
+        assert_eq!(
+            source.substitute(&Name::from("T"), &Type::string()).render_string(),
+            "context(kotlin.String) kotlin.String.(kotlin.collections.Map<in kotlin.String, R>, kotlin.String) -> @a.A kotlin.String?"
+        );
+        assert_eq!(
+            source.substitute(&Name::from("T"), &Type::from_str("() -> kotlin.Unit").unwrap()).render_string(),
+            "context(() -> kotlin.Unit) (() -> kotlin.Unit).(kotlin.collections.Map<in () -> kotlin.Unit, R>, () -> kotlin.Unit) -> (@a.A () -> kotlin.Unit)?"
+        );
+    }
+
+    #[test]
+    fn render_stdlib_factories() {
+        assert_eq!(Type::uint().render_string(), "kotlin.UInt");
+        assert_eq!(Type::int_array().render_string(), "kotlin.IntArray");
+        assert_eq!(Type::char_sequence().render_string(), "kotlin.CharSequence");
+        assert_eq!(
+            Type::mutable_map(Type::string(), Type::int()).render_string(),
+            "kotlin.collections.MutableMap<kotlin.String, kotlin.Int>"
+        );
+        assert_eq!(
+            Type::sequence(Type::string()).render_string(),
+            "kotlin.sequences.Sequence<kotlin.String>"
+        );
+        assert_eq!(
+            Type::triple(Type::string(), Type::int(), Type::boolean()).render_string(),
+            "kotlin.Triple<kotlin.String, kotlin.Int, kotlin.Boolean>"
+        );
+    }
+
+    #[test]
+    fn collection_factories_accept_projections() {
+        assert_eq!(
+            Type::map(Type::string(), TypeProjection::star()).render_string(),
+            "kotlin.collections.Map<kotlin.String, *>"
+        );
+        assert_eq!(
+            Type::array(TypeProjection::covariant(Type::any())).render_string(),
+            "kotlin.Array<out kotlin.Any>"
+        );
+    }
+
+    #[test]
+    fn render_nullable_generic() {
+        assert_eq!(Type::generic("T").nullable(true).render_string(), "T?");
+    }
+
+    #[test]
+    fn render_nullable_function() {
+        let function = Type::Function(
+            FunctionType::new(Type::unit()).parameter(Type::int())
+        );
+        assert_eq!(function.nullable(true).render_string(), "((kotlin.Int) -> kotlin.Unit)?");
+    }
+
+    #[test]
+    fn nullability_is_canonical() {
+        assert_eq!(Type::string().nullable(true), Type::from(ClassLikeType::from("kotlin.String?")));
+        assert_eq!(Type::generic("T").nullable(true).nullable(true), Type::generic("T").nullable(true));
+        assert_eq!(Type::generic("T").nullable(true).nullable(false), Type::generic("T"));
+        assert!(Type::generic("T").nullable(true).is_nullable());
+        assert!(Type::string().nullable(true).is_nullable());
+        assert!(!Type::generic("T").is_nullable());
+    }
+
+    #[test]
+    fn render_definitely_non_nullable() {
+        let generic = Type::generic("T").nullable(true).definitely_non_nullable();
+        assert_eq!(generic.render_string(), "T & kotlin.Any");
+        assert_eq!(Type::string().nullable(true).definitely_non_nullable(), Type::string());
+        assert_eq!(generic.nullable(true), Type::generic("T").nullable(true));
+    }
+
+    #[test]
+    fn parse_nullable_and_definitely_non_nullable() {
+        assert_eq!(Type::from_str("T?").unwrap(), Type::generic("T").nullable(true));
+        assert_eq!(
+            Type::from_str("(() -> kotlin.Unit)?").unwrap(),
+            Type::Function(FunctionType::new(Type::unit())).nullable(true)
+        );
+        assert_eq!(Type::from_str("T & Any").unwrap(), Type::generic("T").definitely_non_nullable());
+        assert_eq!(
+            Type::from_str("kotlin.collections.List<T & kotlin.Any>").unwrap(),
+            Type::list(Type::generic("T").definitely_non_nullable())
+        );
+        assert_eq!(Type::from_str("T & String").unwrap_err().position(), Some(4));
+    }
+
+    fn annotation(name: &str) -> Annotation {
+        Annotation::new(ClassLikeTypeName::from_str(name).unwrap())
+    }
+
+    #[test]
+    fn render_annotated() {
+        let annotated = Type::string()
+            .annotation(annotation("a.A"))
+            .annotation(annotation("a.B"));
+        assert_eq!(annotated.render_string(), "@a.A @a.B kotlin.String");
+        assert_eq!(annotated.clone().nullable(true).render_string(), "@a.A @a.B kotlin.String?");
+
+        let Type::Annotated(annotated) = annotated else { panic!("expected annotated type") };
+        assert_eq!(annotated.annotations().len(), 2);
+        assert_eq!(annotated.inner(), &Type::string());
+    }
+
+    #[test]
+    fn render_annotated_with_arguments() {
+        let annotated = Type::string()
+            .annotation(annotation("a.A").argument(Argument::new_positional(CodeBlock::atom("1"))));
+        assert_eq!(annotated.render_string(), "@a.A(1) kotlin.String");
+    }
+
+    #[test]
+    fn render_annotated_generic_argument() {
+        let list = Type::list(Type::generic("T").annotation(annotation("kotlin.jvm.JvmSuppressWildcards")));
+        assert_eq!(list.render_string(), "kotlin.collections.List<@kotlin.jvm.JvmSuppressWildcards T>");
+    }
+
+    #[test]
+    fn render_annotated_nullable_function() {
+        let function = Type::Function(FunctionType::new(Type::unit()));
+        let expected = "(@a.Composable () -> kotlin.Unit)?";
+
+        assert_eq!(
+            function.clone().annotation(annotation("a.Composable")).nullable(true).render_string(),
+            expected
+        );
+        assert_eq!(
+            function.clone().nullable(true).annotation(annotation("a.Composable")).render_string(),
+            expected
+        );
+        assert_eq!(
+            function.annotation(annotation("a.Composable")).nullable(true).nullable(false).render_string(),
+            "@a.Composable () -> kotlin.Unit"
+        );
+    }
+
+    #[test]
+    fn render_annotated_receiver() {
+        let function = FunctionType::new(Type::unit())
+            .receiver(Type::generic("T").annotation(annotation("a.A")));
+        assert_eq!(function.render_string(), "(@a.A T).() -> kotlin.Unit");
+    }
+
+    #[test]
+    fn parse_annotated() {
+        assert_eq!(
+            Type::from_str("@a.Composable () -> kotlin.Unit").unwrap(),
+            Type::Function(FunctionType::new(Type::unit())).annotation(annotation("a.Composable"))
+        );
+        assert_eq!(
+            Type::from_str("(@a.Composable () -> kotlin.Unit)?").unwrap(),
+            Type::Function(FunctionType::new(Type::unit())).annotation(annotation("a.Composable")).nullable(true)
+        );
     }
 
     #[test]
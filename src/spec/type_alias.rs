@@ -45,6 +45,11 @@ impl TypeAlias {
         self
     }
 
+    /// Name of the declaration
+    pub(crate) fn name(&self) -> &Name {
+        &self.name
+    }
+
     mixin_annotation_mutators!();
     mixin_kdoc_mutators!();
 }
@@ -73,7 +78,7 @@ impl RenderKotlin for TypeAlias {
 
 #[cfg(test)]
 mod tests {
-    use crate::spec::{ClassLikeTypeName, Package, Type};
+    use crate::spec::{ClassLikeTypeName, FunctionType, Package, Type};
 
     use super::*;
 
@@ -113,6 +118,20 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn type_alias_with_named_function_parameters() {
+        let alias = TypeAlias::new(
+            Name::from("OnUserLoaded"),
+            FunctionType::new(Type::unit())
+                .named_parameter("userId", Type::string())
+                .named_parameter("retry", Type::boolean()),
+        );
+
+        let actual = alias.render_string();
+        let expected = "public typealias OnUserLoaded = (userId: kotlin.String, retry: kotlin.Boolean) -> kotlin.Unit";
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn type_alias_with_generic() {
         let alias = TypeAlias::new(
@@ -0,0 +1,784 @@
+use std::str::FromStr;
+
+use crate::io::RenderKotlin;
+use crate::spec::{Annotation, Argument, ClassLikeType, ClassLikeTypeName, CodeBlock, FunctionType, GenericInvariance, Name, Package, Type, TypeProjection};
+use crate::tokens;
+use crate::util::SemanticConversionError;
+
+/// Recursive descent parser of Kotlin type syntax, backs [Type::from_str].
+///
+/// Supported syntax:
+/// - class like types, including nested generics and metadata form `a/b/Outer.Inner`
+/// - star projections and use-site variance of generic arguments
+/// - generic types, only type-parameter-shaped names (single uppercase letter with optional digits, e.g. `T`, `K2`)
+///   are treated as generic, other undotted names like `String` are class like types in root package
+/// - function types with receivers and `suspend` modifier
+/// - nullability marks, definitely non-nullable types and parenthesized types
+/// - type-use annotations, annotation arguments are kept as raw code
+pub(crate) struct TypeParser<'a> {
+    source: &'a str,
+    position: usize,
+}
+
+impl<'a> TypeParser<'a> {
+    /// Parses whole [source] as a single [Type]
+    pub(crate) fn parse(source: &'a str) -> Result<Type, SemanticConversionError> {
+        let mut parser = TypeParser {
+            source,
+            position: 0,
+        };
+
+        let parsed = parser.parse_type()?;
+        parser.skip_whitespace();
+        if !parser.is_at_end() {
+            return Err(parser.error("Unexpected input after type"));
+        }
+
+        Ok(parsed)
+    }
+
+    fn parse_type(&mut self) -> Result<Type, SemanticConversionError> {
+        self.skip_whitespace();
+        let start = self.position;
+
+        if self.peek() == Some('@') {
+            let mut annotations = Vec::new();
+            while self.peek() == Some('@') {
+                annotations.push(self.parse_annotation()?);
+                self.skip_whitespace();
+            }
+
+            return Ok(annotations.into_iter().fold(self.parse_type()?, Type::annotation));
+        }
+
+        if self.rest().strip_prefix(tokens::keyword::CONTEXT)
+            .is_some_and(|rest| rest.trim_start().starts_with(tokens::ROUND_BRACKET_LEFT)) {
+            return self.parse_context_receivers(start);
+        }
+
+        if self.consume_keyword(tokens::keyword::SUSPEND) {
+            self.skip_whitespace();
+            return match self.parse_type()? {
+                Type::Function(function_type) => Ok(Type::Function(function_type.suspended(true))),
+                _ => Err(SemanticConversionError::new_at(
+                    "`suspend` modifier is only applicable to function types",
+                    start,
+                ))
+            };
+        }
+
+        let base = if self.peek() == Some('(') {
+            self.parse_parenthesized()?
+        } else {
+            self.parse_user_type()?
+        };
+
+        let base = self.parse_definitely_non_nullable_suffix(base, start)?;
+
+        if self.is_receiver_separator() {
+            self.position += tokens::DOT.len();
+            self.skip_whitespace();
+            let function_start = self.position;
+            return match self.parse_parenthesized()? {
+                Type::Function(function_type) => Ok(Type::Function(function_type.receiver(base))),
+                _ => Err(SemanticConversionError::new_at(
+                    "Expected function type after receiver",
+                    function_start,
+                ))
+            };
+        }
+
+        Ok(base)
+    }
+
+    /// Parses function type with context receivers, e.g. `context(A, B) () -> C`
+    fn parse_context_receivers(&mut self, start: usize) -> Result<Type, SemanticConversionError> {
+        self.position += tokens::keyword::CONTEXT.len();
+        self.skip_whitespace();
+        self.expect(tokens::ROUND_BRACKET_LEFT)?;
+
+        let mut context_receivers = Vec::new();
+        loop {
+            context_receivers.push(self.parse_type()?);
+            self.skip_whitespace();
+            if self.consume(tokens::COMMA) {
+                self.skip_whitespace();
+                if self.consume(tokens::ROUND_BRACKET_RIGHT) {
+                    break;
+                }
+                continue;
+            }
+
+            self.expect(tokens::ROUND_BRACKET_RIGHT)?;
+            break;
+        }
+
+        match self.parse_type()? {
+            Type::Function(function_type) => Ok(Type::Function(
+                context_receivers.into_iter().fold(function_type, FunctionType::context_receiver)
+            )),
+            _ => Err(SemanticConversionError::new_at(
+                "Context receivers are only applicable to function types",
+                start,
+            ))
+        }
+    }
+
+    /// Parses optional `& Any` part of definitely non-nullable type `T & Any`
+    fn parse_definitely_non_nullable_suffix(&mut self, base: Type, start: usize) -> Result<Type, SemanticConversionError> {
+        let before_whitespace = self.position;
+        self.skip_whitespace();
+        if !self.consume(tokens::AMPERSAND) {
+            self.position = before_whitespace;
+            return Ok(base);
+        }
+
+        self.skip_whitespace();
+        let any_start = self.position;
+        let any = self.parse_user_type()?;
+        if any.render_string() != "Any" && any != Type::any() {
+            return Err(SemanticConversionError::new_at(
+                "Right side of definitely non-nullable type must be `Any`",
+                any_start,
+            ));
+        }
+
+        if !matches!(base, Type::Generic(_)) {
+            return Err(SemanticConversionError::new_at(
+                "Only generic types can be definitely non-nullable",
+                start,
+            ));
+        }
+
+        Ok(base.definitely_non_nullable())
+    }
+
+    /// Parses either function type `(A, B) -> C` or parenthesized type `(A)`
+    fn parse_parenthesized(&mut self) -> Result<Type, SemanticConversionError> {
+        self.expect(tokens::ROUND_BRACKET_LEFT)?;
+
+        let mut elements = Vec::new();
+        let mut has_trailing_comma = false;
+        loop {
+            self.skip_whitespace();
+            if self.consume(tokens::ROUND_BRACKET_RIGHT) {
+                break;
+            }
+
+            let name = self.parse_parameter_name();
+            elements.push((name, self.parse_type()?));
+            self.skip_whitespace();
+
+            if self.consume(tokens::COMMA) {
+                has_trailing_comma = true;
+                continue;
+            }
+
+            has_trailing_comma = false;
+            self.expect(tokens::ROUND_BRACKET_RIGHT)?;
+            break;
+        }
+
+        let after_brackets = self.position;
+        self.skip_whitespace();
+        if self.consume(tokens::ARROW) {
+            let returns = self.parse_type()?;
+            let mut function_type = FunctionType::new(returns);
+            for (name, parameter) in elements {
+                function_type = match name {
+                    Some(name) => function_type.named_parameter(name, parameter),
+                    None => function_type.parameter(parameter),
+                };
+            }
+            return Ok(Type::Function(function_type));
+        }
+        self.position = after_brackets;
+
+        if elements.len() != 1 || has_trailing_comma {
+            return Err(SemanticConversionError::new_at(
+                "Expected `->` after function type parameters",
+                self.position,
+            ));
+        }
+
+        let (name, inner) = elements.remove(0);
+        if name.is_some() {
+            return Err(SemanticConversionError::new_at(
+                "Expected `->` after function type parameters",
+                self.position,
+            ));
+        }
+        let is_nullable = self.consume_nullability_marks();
+        Ok(inner.nullable(is_nullable))
+    }
+
+    /// Parses optional `name:` prefix of function type parameter, restores position if there is none
+    fn parse_parameter_name(&mut self) -> Option<Name> {
+        let start = self.position;
+        if let Ok(name) = self.parse_name() {
+            self.skip_whitespace();
+            if self.consume(tokens::COLON) {
+                self.skip_whitespace();
+                return Some(name);
+            }
+        }
+
+        self.position = start;
+        None
+    }
+
+    /// Parses class like or generic type, for example `kotlin.collections.Map<K, V>?` or `T`
+    fn parse_user_type(&mut self) -> Result<Type, SemanticConversionError> {
+        let (mut segments, package_length) = self.parse_qualified_name()?;
+
+        let mut generic_arguments = Vec::new();
+        if self.peek() == Some('<') {
+            generic_arguments = self.parse_generic_arguments()?;
+            if self.peek() == Some('.') && !self.is_receiver_separator() {
+                return Err(self.error("Generic arguments are only supported on the innermost class"));
+            }
+        }
+
+        let is_nullable = self.consume_nullability_marks();
+
+        if package_length.is_none()
+            && segments.len() == 1
+            && generic_arguments.is_empty()
+            && Self::is_type_parameter_name(&segments[0]) {
+            return Ok(Type::Generic(segments.remove(0)).nullable(is_nullable));
+        }
+
+        let mut class_like_type = ClassLikeType::new(
+            Self::to_class_like_type_name(segments, package_length)
+        ).nullable(is_nullable);
+
+        for generic_argument in generic_arguments {
+            class_like_type = class_like_type.generic_argument(generic_argument);
+        }
+
+        Ok(Type::ClassLike(class_like_type))
+    }
+
+    /// Parses dot or slash separated names, returns them with number of package segments if `/` was used
+    fn parse_qualified_name(&mut self) -> Result<(Vec<Name>, Option<usize>), SemanticConversionError> {
+        let mut segments = vec![self.parse_name()?];
+        let mut package_length: Option<usize> = None;
+        let mut has_dot = false;
+
+        loop {
+            if self.peek() == Some('/') {
+                if has_dot {
+                    return Err(self.error("Package separator `/` can't follow class name separator `.`"));
+                }
+                self.position += tokens::SLASH.len();
+                package_length = Some(segments.len());
+            } else if self.peek() == Some('.') && !self.is_receiver_separator() {
+                self.position += tokens::DOT.len();
+                has_dot = true;
+            } else {
+                break;
+            }
+
+            segments.push(self.parse_name()?);
+        }
+
+        Ok((segments, package_length))
+    }
+
+    fn to_class_like_type_name(mut segments: Vec<Name>, package_length: Option<usize>) -> ClassLikeTypeName {
+        let package_length = package_length.unwrap_or(segments.len() - 1);
+        let names = segments.split_off(package_length);
+        ClassLikeTypeName::nested(Package::from(segments), names)
+    }
+
+    /// Parses annotation, for example `@a.Annotation(name = "value")`.
+    /// Arguments are only recognized if they immediately follow annotation name, like in Kotlin.
+    fn parse_annotation(&mut self) -> Result<Annotation, SemanticConversionError> {
+        self.expect(tokens::AT)?;
+        let (segments, package_length) = self.parse_qualified_name()?;
+        let mut annotation = Annotation::new(Self::to_class_like_type_name(segments, package_length));
+
+        if self.peek() == Some('(') {
+            for argument in self.parse_annotation_arguments()? {
+                annotation = annotation.argument(argument);
+            }
+        }
+
+        Ok(annotation)
+    }
+
+    fn parse_annotation_arguments(&mut self) -> Result<Vec<Argument>, SemanticConversionError> {
+        let start = self.position;
+        self.expect(tokens::ROUND_BRACKET_LEFT)?;
+        let arguments_start = self.position;
+
+        let mut depth = 0;
+        let mut string_delimiter: Option<char> = None;
+        let mut is_escaped = false;
+        let mut separators = vec![];
+        for (offset, ch) in self.rest().char_indices() {
+            if let Some(delimiter) = string_delimiter {
+                match ch {
+                    _ if is_escaped => is_escaped = false,
+                    '\\' => is_escaped = true,
+                    _ if ch == delimiter => string_delimiter = None,
+                    _ => {}
+                }
+                continue;
+            }
+
+            match ch {
+                '"' | '\'' => string_delimiter = Some(ch),
+                '(' | '[' | '{' => depth += 1,
+                ')' if depth == 0 => {
+                    separators.push(offset);
+                    let arguments_source = &self.source[arguments_start..arguments_start + offset];
+                    self.position = arguments_start + offset + tokens::ROUND_BRACKET_RIGHT.len();
+                    return Self::split_annotation_arguments(arguments_source, &separators[..separators.len() - 1]);
+                }
+                ')' | ']' | '}' => depth -= 1,
+                ',' if depth == 0 => separators.push(offset),
+                _ => {}
+            }
+        }
+
+        Err(SemanticConversionError::new_at("Unterminated annotation arguments", start))
+    }
+
+    fn split_annotation_arguments(source: &str, separators: &[usize]) -> Result<Vec<Argument>, SemanticConversionError> {
+        let mut arguments = Vec::new();
+        let mut argument_start = 0;
+        for end in separators.iter().copied().chain(std::iter::once(source.len())) {
+            let argument = source[argument_start..end].trim();
+            argument_start = end + tokens::COMMA.len();
+            if argument.is_empty() {
+                continue;
+            }
+
+            let named = argument.split_once(tokens::ASSIGN)
+                .filter(|(name, value)| {
+                    !value.starts_with(tokens::ASSIGN) && !name.trim().is_empty()
+                        && name.trim().chars().all(Self::is_identifier_char)
+                });
+            arguments.push(match named {
+                Some((name, value)) => Argument::new_named(
+                    Name::from_str(name.trim())?,
+                    CodeBlock::atom(value.trim()),
+                ),
+                None => Argument::new_positional(CodeBlock::atom(argument)),
+            });
+        }
+
+        Ok(arguments)
+    }
+
+    fn parse_generic_arguments(&mut self) -> Result<Vec<TypeProjection>, SemanticConversionError> {
+        self.expect(tokens::ANGLE_BRACKET_LEFT)?;
+        let mut arguments = Vec::new();
+
+        loop {
+            self.skip_whitespace();
+            arguments.push(self.parse_type_projection()?);
+            self.skip_whitespace();
+
+            if self.consume(tokens::COMMA) {
+                continue;
+            }
+
+            self.expect(tokens::ANGLE_BRACKET_RIGHT)?;
+            return Ok(arguments);
+        }
+    }
+
+    fn parse_type_projection(&mut self) -> Result<TypeProjection, SemanticConversionError> {
+        if self.consume(tokens::STAR) {
+            return Ok(TypeProjection::star());
+        }
+
+        let variance = if self.consume_keyword(tokens::keyword::IN) {
+            Some(GenericInvariance::In)
+        } else if self.consume_keyword(tokens::keyword::OUT) {
+            Some(GenericInvariance::Out)
+        } else {
+            None
+        };
+
+        Ok(TypeProjection::Type {
+            variance,
+            projected_type: self.parse_type()?,
+        })
+    }
+
+    /// Parses single identifier, possibly escaped with backticks
+    fn parse_name(&mut self) -> Result<Name, SemanticConversionError> {
+        let start = self.position;
+        let text = if self.consume(tokens::TICK) {
+            let Some(length) = self.rest().find(tokens::TICK) else {
+                return Err(SemanticConversionError::new_at("Unterminated escaped identifier", start));
+            };
+            let text = &self.source[self.position..self.position + length];
+            self.position += length + tokens::TICK.len();
+            text
+        } else {
+            let length = self.rest()
+                .find(|ch: char| !Self::is_identifier_char(ch))
+                .unwrap_or(self.rest().len());
+            let text = &self.source[self.position..self.position + length];
+            if text.is_empty() || text.starts_with(|ch: char| ch.is_ascii_digit()) {
+                return Err(self.error("Expected identifier"));
+            }
+            if tokens::keyword::HARD.contains(&text) {
+                return Err(self.error(format!("Keyword `{}` can't be used as identifier", text).as_str()));
+            }
+            self.position += length;
+            text
+        };
+
+        Name::from_str(text)
+            .map_err(|error| SemanticConversionError::new_at(error.message(), start))
+    }
+
+    /// Checks if [name] looks like type parameter: single uppercase letter with optional digits, e.g. `T`, `K2`
+    fn is_type_parameter_name(name: &Name) -> bool {
+        let rendered = name.render_string();
+        let mut chars = rendered.chars();
+        chars.next().is_some_and(|ch| ch.is_ascii_uppercase()) && chars.all(|ch| ch.is_ascii_digit())
+    }
+
+    fn is_identifier_char(ch: char) -> bool {
+        ch.is_alphanumeric() || ch == '_'
+    }
+
+    /// Checks if next tokens are `.(`, separating function type receiver from its parameters
+    fn is_receiver_separator(&self) -> bool {
+        self.rest().strip_prefix(tokens::DOT)
+            .is_some_and(|rest| rest.trim_start().starts_with(tokens::ROUND_BRACKET_LEFT))
+    }
+
+    fn consume_nullability_marks(&mut self) -> bool {
+        let mut is_nullable = false;
+        while self.consume(tokens::QUESTION_MARK) {
+            is_nullable = true;
+        }
+        is_nullable
+    }
+
+    fn consume_keyword(&mut self, keyword: &str) -> bool {
+        let Some(rest) = self.rest().strip_prefix(keyword) else {
+            return false;
+        };
+        if !rest.starts_with(|ch: char| ch.is_whitespace() || ch == '(') {
+            return false;
+        }
+        self.position += keyword.len();
+        true
+    }
+
+    fn consume(&mut self, token: &str) -> bool {
+        if self.rest().starts_with(token) {
+            self.position += token.len();
+            return true;
+        }
+        false
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), SemanticConversionError> {
+        if self.consume(token) {
+            return Ok(());
+        }
+        Err(self.error(format!("Expected `{}`", token).as_str()))
+    }
+
+    fn skip_whitespace(&mut self) {
+        let trimmed = self.rest().trim_start();
+        self.position = self.source.len() - trimmed.len();
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.source[self.position..]
+    }
+
+    fn is_at_end(&self) -> bool {
+        self.position >= self.source.len()
+    }
+
+    fn error(&self, message: &str) -> SemanticConversionError {
+        SemanticConversionError::new_at(message, self.position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::RenderKotlin;
+    use crate::spec::{Annotation, Argument, ClassLikeType, ClassLikeTypeName, CodeBlock, FunctionType, Name, Package, Type, TypeProjection};
+
+    use super::TypeParser;
+
+    fn assert_round_trip(source: &str) {
+        assert_eq!(TypeParser::parse(source).unwrap().render_string(), source);
+    }
+
+    #[test]
+    fn parse_class_like() {
+        assert_eq!(TypeParser::parse("kotlin.String").unwrap(), Type::string());
+        assert_eq!(
+            TypeParser::parse("Box<T>").unwrap(),
+            Type::from(ClassLikeType::new(ClassLikeTypeName::top_level(Package::root(), "Box")).generic_argument(Type::generic("T")))
+        );
+    }
+
+    #[test]
+    fn parse_generic() {
+        assert_eq!(TypeParser::parse("T").unwrap(), Type::generic("T"));
+        assert_eq!(TypeParser::parse("R1").unwrap(), Type::generic("R1"));
+        assert_eq!(TypeParser::parse("T?").unwrap(), Type::generic("T").nullable(true));
+    }
+
+    #[test]
+    fn parse_undotted_class_like() {
+        let string = TypeParser::parse("String").unwrap();
+        assert!(!matches!(string, Type::Generic(_)));
+        assert_eq!(string, Type::from(ClassLikeTypeName::top_level(Package::root(), "String")));
+        assert_eq!(
+            TypeParser::parse("Element?").unwrap(),
+            Type::from(ClassLikeTypeName::top_level(Package::root(), "Element")).nullable(true)
+        );
+    }
+
+    #[test]
+    fn parse_keywords_without_whitespace() {
+        assert_eq!(
+            TypeParser::parse("suspend() -> kotlin.Unit").unwrap(),
+            Type::from(FunctionType::new(Type::unit()).suspended(true))
+        );
+        assert_eq!(
+            TypeParser::parse("context (a.A) () -> kotlin.Unit").unwrap(),
+            Type::from(FunctionType::new(Type::unit()).context_receiver(Type::from(ClassLikeTypeName::from("a.A"))))
+        );
+    }
+
+    #[test]
+    fn reject_hard_keywords() {
+        let error = TypeParser::parse("List<in>").unwrap_err();
+        assert_eq!(error.position(), Some(5));
+        assert!(TypeParser::parse("a.class.B").is_err());
+        assert_eq!(
+            TypeParser::parse("a.`in`").unwrap(),
+            Type::from(ClassLikeTypeName::top_level(Package::from(vec![Name::from("a")]), "in"))
+        );
+    }
+
+    #[test]
+    fn parse_nested_generics() {
+        assert_eq!(
+            TypeParser::parse("kotlin.collections.Map<kotlin.String, kotlin.collections.List<T>>").unwrap(),
+            Type::map(Type::string(), Type::list(Type::generic("T")))
+        );
+        assert_round_trip("kotlin.collections.Map<kotlin.String, kotlin.collections.List<kotlin.Int?>>?");
+    }
+
+    #[test]
+    fn parse_escaped_names() {
+        assert_eq!(
+            TypeParser::parse("a.`my class`").unwrap(),
+            Type::from(ClassLikeTypeName::top_level(Package::from(vec![Name::from("a")]), "my class"))
+        );
+    }
+
+    #[test]
+    fn parse_function_types() {
+        assert_eq!(
+            TypeParser::parse("(kotlin.Int, kotlin.String) -> kotlin.Unit").unwrap(),
+            Type::Function(
+                FunctionType::new(Type::unit())
+                    .parameter(Type::int())
+                    .parameter(Type::string())
+            )
+        );
+        assert_round_trip("() -> kotlin.Unit");
+        assert_round_trip("(kotlin.Int) -> (kotlin.String) -> kotlin.Unit");
+        assert_round_trip("((kotlin.Int) -> kotlin.Unit) -> kotlin.Unit");
+    }
+
+    #[test]
+    fn parse_function_types_with_receiver() {
+        assert_eq!(
+            TypeParser::parse("kotlin.String.(kotlin.Int) -> kotlin.Unit").unwrap(),
+            Type::Function(
+                FunctionType::new(Type::unit())
+                    .receiver(Type::string())
+                    .parameter(Type::int())
+            )
+        );
+        assert_round_trip("kotlin.collections.List<T>?.() -> T");
+        assert_round_trip("suspend a.b.Scope.() -> kotlin.Unit");
+        assert_round_trip("(() -> kotlin.Unit).() -> kotlin.Unit");
+        assert_round_trip("(() -> kotlin.Unit)?.() -> kotlin.Unit");
+        assert_round_trip("(T & kotlin.Any).() -> kotlin.Unit");
+    }
+
+    #[test]
+    fn parse_suspend_function_types() {
+        assert_eq!(
+            TypeParser::parse("suspend () -> kotlin.Unit").unwrap(),
+            Type::Function(FunctionType::new(Type::unit()).suspended(true))
+        );
+        assert_round_trip("suspend (kotlin.Int) -> suspend () -> kotlin.Unit");
+    }
+
+    #[test]
+    fn parse_parenthesized() {
+        assert_eq!(TypeParser::parse("(kotlin.String)").unwrap(), Type::string());
+        assert_eq!(
+            TypeParser::parse("(kotlin.String)?").unwrap(),
+            Type::ClassLike(ClassLikeType::from("kotlin.String?"))
+        );
+    }
+
+    #[test]
+    fn parse_nullable() {
+        assert_eq!(TypeParser::parse("T?").unwrap(), Type::generic("T").nullable(true));
+        assert_round_trip("((kotlin.Int) -> kotlin.Unit)?");
+        assert_round_trip("(kotlin.Int) -> kotlin.Unit?");
+        assert_round_trip("suspend () -> T?");
+    }
+
+    #[test]
+    fn parse_definitely_non_nullable() {
+        assert_eq!(
+            TypeParser::parse("T & Any").unwrap(),
+            Type::generic("T").definitely_non_nullable()
+        );
+        assert_round_trip("(T & kotlin.Any) -> T & kotlin.Any");
+
+        let error = TypeParser::parse("kotlin.String & Any").unwrap_err();
+        assert_eq!(error.position(), Some(0));
+    }
+
+    #[test]
+    fn parse_annotated() {
+        assert_eq!(
+            TypeParser::parse("@a.Composable () -> kotlin.Unit").unwrap(),
+            Type::Function(FunctionType::new(Type::unit()))
+                .annotation(Annotation::new(ClassLikeTypeName::from("a.Composable")))
+        );
+        assert_round_trip("kotlin.collections.List<@kotlin.jvm.JvmSuppressWildcards T>");
+        assert_round_trip("(@a.Composable @a.Other () -> kotlin.Unit)?");
+        assert_round_trip("@a.A T?");
+    }
+
+    #[test]
+    fn parse_annotation_arguments() {
+        let parsed = TypeParser::parse("@a.A(1, name = \"x, (y)\", other = f(1, 2)) kotlin.String").unwrap();
+        assert_eq!(
+            parsed,
+            Type::string().annotation(
+                Annotation::new(ClassLikeTypeName::from("a.A"))
+                    .argument(Argument::new_positional(CodeBlock::atom("1")))
+                    .argument(Argument::new_named("name", CodeBlock::atom("\"x, (y)\"")))
+                    .argument(Argument::new_named("other", CodeBlock::atom("f(1, 2)")))
+            )
+        );
+        assert_eq!(
+            parsed.render_string(),
+            "@a.A(1, name = \"x, (y)\", other = f(1, 2)) kotlin.String"
+        );
+    }
+
+    #[test]
+    fn report_unterminated_annotation_arguments() {
+        let error = TypeParser::parse("@a.A(\")\" kotlin.String").unwrap_err();
+        assert_eq!(error.position(), Some(4));
+    }
+
+    #[test]
+    fn parse_named_function_parameters() {
+        assert_eq!(
+            TypeParser::parse("(userId: kotlin.String, kotlin.Int, `is retry`: kotlin.Boolean) -> kotlin.Unit").unwrap(),
+            Type::Function(
+                FunctionType::new(Type::unit())
+                    .named_parameter("userId", Type::string())
+                    .parameter(Type::int())
+                    .named_parameter("is retry", Type::boolean())
+            )
+        );
+        assert_round_trip("(userId: kotlin.String, retry: kotlin.Boolean) -> kotlin.Unit");
+        assert!(TypeParser::parse("(name: kotlin.String)").is_err());
+    }
+
+    #[test]
+    fn parse_context_receivers() {
+        assert_eq!(
+            TypeParser::parse("context(a.Logger, T) () -> kotlin.Unit").unwrap(),
+            Type::Function(
+                FunctionType::new(Type::unit())
+                    .context_receiver(ClassLikeTypeName::from("a.Logger"))
+                    .context_receiver(Type::generic("T"))
+            )
+        );
+        assert_round_trip("context(a.Logger) suspend kotlin.String.(kotlin.Int) -> kotlin.Unit");
+        assert_round_trip("(context(a.Logger) () -> kotlin.Unit)?");
+        assert_eq!(TypeParser::parse("context(a.Logger) kotlin.String").unwrap_err().position(), Some(0));
+    }
+
+    #[test]
+    fn parse_with_whitespaces() {
+        assert_eq!(
+            TypeParser::parse("  ( kotlin.Int , )->kotlin.collections.List< T >  ").unwrap(),
+            Type::Function(
+                FunctionType::new(Type::list(Type::generic("T")))
+                    .parameter(Type::int())
+            )
+        );
+    }
+
+    #[test]
+    fn report_error_positions() {
+        let error = TypeParser::parse("kotlin.collections.List<kotlin.String").unwrap_err();
+        assert_eq!(error.position(), Some(37));
+
+        let error = TypeParser::parse("(kotlin.Int, kotlin.String)").unwrap_err();
+        assert_eq!(error.position(), Some(27));
+
+        let error = TypeParser::parse("suspend kotlin.String").unwrap_err();
+        assert_eq!(error.position(), Some(0));
+
+        let error = TypeParser::parse("kotlin.String kotlin.Int").unwrap_err();
+        assert_eq!(error.position(), Some(14));
+
+        let error = TypeParser::parse("kotlin..String").unwrap_err();
+        assert_eq!(error.position(), Some(7));
+
+        let error = TypeParser::parse("a.Outer<T>.Inner").unwrap_err();
+        assert_eq!(error.position(), Some(10));
+    }
+
+    #[test]
+    fn parse_projections() {
+        assert_eq!(
+            TypeParser::parse("kotlin.collections.Map<*, out kotlin.collections.List<in T>>").unwrap(),
+            Type::map(
+                TypeProjection::star(),
+                TypeProjection::covariant(Type::list(TypeProjection::contravariant(Type::generic("T"))))
+            )
+        );
+        assert_round_trip("kotlin.Array<out (kotlin.Int) -> kotlin.Unit>");
+        assert_eq!(
+            TypeParser::parse("a.Box<inner.Type>").unwrap().render_string(),
+            "a.Box<inner.Type>"
+        );
+    }
+
+    #[test]
+    fn report_invalid_projections() {
+        let error = TypeParser::parse("kotlin.collections.List<in >").unwrap_err();
+        assert_eq!(error.position(), Some(27));
+
+        let error = TypeParser::parse("kotlin.collections.List<**>").unwrap_err();
+        assert_eq!(error.position(), Some(25));
+    }
+}
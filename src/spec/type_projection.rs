@@ -0,0 +1,136 @@
+use crate::io::RenderKotlin;
+use crate::spec::{ClassLikeType, ClassLikeTypeName, CodeBlock, GenericInvariance, Type};
+use crate::tokens;
+
+/// Generic argument of a class like type, a.k.a. [type projection](https://kotlinlang.org/docs/generics.html#type-projections).
+///
+/// Any [Type] converts into invariant projection, so it can be passed wherever [TypeProjection] is expected.
+/// Use-site variance is expressed via [GenericInvariance].
+///
+/// # Examples
+/// ```rust
+/// use kotlin_poet_rs::io::RenderKotlin;
+/// use kotlin_poet_rs::spec::{ClassLikeType, ClassLikeTypeName, Type, TypeProjection};
+///
+/// let comparator = ClassLikeType::new(ClassLikeTypeName::from("java.util.Comparator"))
+///     .generic_argument(TypeProjection::contravariant(Type::generic("T")));
+///
+/// assert_eq!(comparator.render_string(), "java.util.Comparator<in T>");
+/// assert_eq!(Type::list(TypeProjection::star()).render_string(), "kotlin.collections.List<*>");
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone)]
+pub enum TypeProjection {
+    /// Star projection `*`
+    Star,
+    /// Projection of a concrete type, possibly with use-site variance (e.g. `T`, `in T`, `out T`)
+    Type {
+        variance: Option<GenericInvariance>,
+        projected_type: Type,
+    },
+}
+
+impl TypeProjection {
+    /// Creates star projection `*`
+    pub fn star() -> TypeProjection {
+        TypeProjection::Star
+    }
+
+    /// Creates invariant projection `T`
+    pub fn invariant<TypeLike: Into<Type>>(projected_type: TypeLike) -> TypeProjection {
+        TypeProjection::Type {
+            variance: None,
+            projected_type: projected_type.into(),
+        }
+    }
+
+    /// Creates `in T` projection
+    pub fn contravariant<TypeLike: Into<Type>>(projected_type: TypeLike) -> TypeProjection {
+        TypeProjection::Type {
+            variance: Some(GenericInvariance::In),
+            projected_type: projected_type.into(),
+        }
+    }
+
+    /// Projected type, [None] for star projection
+    pub fn projected_type(&self) -> Option<&Type> {
+        match self {
+            TypeProjection::Star => None,
+            TypeProjection::Type { projected_type, .. } => Some(projected_type),
+        }
+    }
+
+    /// Replaces projected type with the result of [transform], keeping variance
+    pub(crate) fn map_type<F: FnMut(Type) -> Type>(self, transform: &mut F) -> TypeProjection {
+        match self {
+            TypeProjection::Star => TypeProjection::Star,
+            TypeProjection::Type { variance, projected_type } => TypeProjection::Type {
+                variance,
+                projected_type: transform(projected_type),
+            },
+        }
+    }
+
+    /// Creates `out T` projection
+    pub fn covariant<TypeLike: Into<Type>>(projected_type: TypeLike) -> TypeProjection {
+        TypeProjection::Type {
+            variance: Some(GenericInvariance::Out),
+            projected_type: projected_type.into(),
+        }
+    }
+}
+
+impl From<Type> for TypeProjection {
+    fn from(value: Type) -> Self {
+        TypeProjection::invariant(value)
+    }
+}
+
+impl From<ClassLikeType> for TypeProjection {
+    fn from(value: ClassLikeType) -> Self {
+        TypeProjection::invariant(value)
+    }
+}
+
+impl From<ClassLikeTypeName> for TypeProjection {
+    fn from(value: ClassLikeTypeName) -> Self {
+        TypeProjection::invariant(value)
+    }
+}
+
+impl RenderKotlin for TypeProjection {
+    fn render_into(&self, block: &mut CodeBlock) {
+        match self {
+            TypeProjection::Star => block.push_static_atom(tokens::STAR),
+            TypeProjection::Type { variance, projected_type } => {
+                if let Some(variance) = variance {
+                    block.push_renderable(variance);
+                    block.push_space();
+                }
+                block.push_renderable(projected_type);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::RenderKotlin;
+    use crate::spec::{Type, TypeProjection};
+
+    #[test]
+    fn render_star() {
+        assert_eq!(TypeProjection::star().render_string(), "*");
+    }
+
+    #[test]
+    fn render_variance() {
+        assert_eq!(TypeProjection::invariant(Type::string()).render_string(), "kotlin.String");
+        assert_eq!(TypeProjection::contravariant(Type::generic("T")).render_string(), "in T");
+        assert_eq!(TypeProjection::covariant(Type::any()).render_string(), "out kotlin.Any");
+    }
+
+    #[test]
+    fn type_converts_to_invariant_projection() {
+        assert_eq!(TypeProjection::from(Type::int()), TypeProjection::invariant(Type::int()));
+    }
+}
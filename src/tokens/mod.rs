@@ -16,6 +16,8 @@ pub const INDENT: &str = "    ";
 // SYMBOLS
 /// '.' used to separate qualifiers
 pub const DOT: &str = ".";
+/// '/' separates package segments in Kotlin metadata class names (e.g. `a/b/Outer.Inner`)
+pub const SLASH: &str = "/";
 /// '*' symbol used for star projections
 pub const STAR: &str = "*";
 /// ':' separates parameter / property name and type
@@ -28,6 +30,8 @@ pub const COMMA: &str = ",";
 pub const SEMICOLON: &str = ";";
 /// '?' denotes nullability
 pub const QUESTION_MARK: &str = "?";
+/// '&' denotes definitely non-nullable types (e.g. `T & Any`)
+pub const AMPERSAND: &str = "&";
 /// `@` used as annotation prefix
 pub const AT: &str = "@";
 /// '`' used to escape non JVM compatible identifiers
@@ -84,9 +88,16 @@ pub mod keyword {
     pub const THIS: &str = "this";
     pub const AS: &str = "as";
     pub const OPERATOR: &str = "operator";
+    /// 'infix' function keyword
+    pub const INFIX: &str = "infix";
+    /// 'tailrec' function keyword
+    pub const TAILREC: &str = "tailrec";
+    /// 'external' keyword, marks declarations implemented natively (e.g. via JNI)
+    pub const EXTERNAL: &str = "external";
     pub const INLINE: &str = "inline";
     pub const OVERRIDE: &str = "override";
     pub const SUSPEND: &str = "suspend";
+    pub const CONTEXT: &str = "context";
     pub const SET: &str = "set";
     pub const GET: &str = "get";
     pub const PACKAGE: &str = "package";
@@ -104,11 +115,25 @@ pub mod keyword {
     pub const ENUM: &str = "enum";
     /// 'data' class keyword
     pub const DATA: &str = "data";
+    /// 'value' class keyword
+    pub const VALUE: &str = "value";
+    /// 'annotation' class keyword
+    pub const ANNOTATION: &str = "annotation";
+    /// 'expect' multiplatform declaration keyword
+    pub const EXPECT: &str = "expect";
+    /// 'actual' multiplatform declaration keyword
+    pub const ACTUAL: &str = "actual";
     pub const INTERFACE: &str = "interface";
     pub const FINAL: &str = "final";
     pub const ABSTRACT: &str = "abstract";
     pub const IMPORT: &str = "import";
     pub const CONST: &str = "const";
+    /// 'vararg' parameter keyword
+    pub const VARARG: &str = "vararg";
+    /// 'noinline' parameter keyword
+    pub const NOINLINE: &str = "noinline";
+    /// 'crossinline' parameter keyword
+    pub const CROSSINLINE: &str = "crossinline";
     /// 'by' keyword
     pub const BY: &str = "by";
     /// 'constructor' keyword
@@ -135,4 +160,12 @@ pub mod keyword {
     pub const PARAM: &str = "param";
     pub const SET_PARAM: &str = "setparam";
     pub const DELEGATE: &str = "delegate";
+
+    /// [Hard keywords](https://kotlinlang.org/docs/keyword-reference.html#hard-keywords),
+    /// can't be used as identifiers unless escaped with backticks
+    pub const HARD: &[&str] = &[
+        AS, "break", CLASS, "continue", "do", "else", "false", "for", FUN, "if", IN, INTERFACE, "is", "null",
+        OBJECT, PACKAGE, "return", "super", THIS, "throw", "true", "try", TYPEALIAS, "typeof", VAL, VAR,
+        "when", "while",
+    ];
 }
\ No newline at end of file
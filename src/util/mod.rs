@@ -1,5 +1,15 @@
 mod semantic_conversion_error;
+mod validation_error;
 pub use semantic_conversion_error::SemanticConversionError;
+pub use validation_error::ValidationError;
+
+use std::cmp::Ordering;
+use crate::io::RenderKotlin;
+
+/// Compares [left] and [right] by their rendered Kotlin code
+pub(crate) fn compare_rendered<T: RenderKotlin>(left: &T, right: &T) -> Ordering {
+    left.render_string().cmp(&right.render_string())
+}
 
 /// A macro that implements `From<&str>` for a type that implements `FromStr`.
 /// Panics if the conversion fails.
@@ -13,4 +23,4 @@ macro_rules! yolo_from_str {
     };
 }
 
-pub(crate) use yolo_from_str;
\ No newline at end of file
+pub(crate) use yolo_from_str;
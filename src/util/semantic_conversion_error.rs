@@ -3,24 +3,47 @@ use std::fmt::Display;
 /// Error type for semantic conversion errors.
 ///
 /// For example when converting [crate::spec::ClassLikeTypeName] to [crate::spec::KotlinFile].
+/// Errors produced by parsers additionally carry position (byte offset) of the problem in the parsed input.
 #[derive(Debug)]
 pub struct SemanticConversionError {
-    message: String
+    message: String,
+    position: Option<usize>,
 }
 
 impl SemanticConversionError {
 
     pub(crate) fn new(message: &str) -> Self {
         SemanticConversionError {
-            message: message.to_string()
+            message: message.to_string(),
+            position: None,
         }
     }
+
+    pub(crate) fn new_at(message: &str, position: usize) -> Self {
+        SemanticConversionError {
+            message: message.to_string(),
+            position: Some(position),
+        }
+    }
+
+    /// Human-readable description of the error
+    pub fn message(&self) -> &str {
+        self.message.as_str()
+    }
+
+    /// Byte offset of the problem in the parsed input, if known
+    pub fn position(&self) -> Option<usize> {
+        self.position
+    }
 }
 
 impl Display for SemanticConversionError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{}", self.message)
+        match self.position {
+            Some(position) => write!(f, "{} at position {}", self.message, position),
+            None => write!(f, "{}", self.message),
+        }
     }
 }
 
-impl std::error::Error for SemanticConversionError {}
\ No newline at end of file
+impl std::error::Error for SemanticConversionError {}
@@ -0,0 +1,31 @@
+use std::fmt::Display;
+
+/// Error type for specs that can be rendered, but would not compile as Kotlin code.
+///
+/// For example [crate::spec::Class] marked as value class without exactly one `val` property in primary constructor.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ValidationError {
+    message: String,
+}
+
+impl ValidationError {
+
+    pub(crate) fn new(message: &str) -> Self {
+        ValidationError {
+            message: message.to_string(),
+        }
+    }
+
+    /// Human-readable description of the error
+    pub fn message(&self) -> &str {
+        self.message.as_str()
+    }
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ValidationError {}
@@ -2,7 +2,7 @@ use std::path::Path;
 use std::str::FromStr;
 
 use kotlin_poet_rs::io::RenderKotlin;
-use kotlin_poet_rs::spec::{VisibilityModifier, Class, ClassInheritanceModifier, CodeBlock, CompanionObject, Function, Parameter, KotlinFile, MemberInheritanceModifier, Name, Package, PrimaryConstructor, Property, PropertyGetter, PropertySetter, Type, KDoc};
+use kotlin_poet_rs::spec::{VisibilityModifier, Class, ClassInheritanceModifier, CodeBlock, CompanionObject, Function, Parameter, KotlinFile, Name, Package, PrimaryConstructor, Property, PropertyGetter, PropertySetter, Type, KDoc};
 
 #[test]
 fn generic_file() {
@@ -25,7 +25,6 @@ fn generic_file() {
         .operator(true)
         .inline(true)
         .visibility_modifier(VisibilityModifier::Private)
-        .inheritance_modifier(MemberInheritanceModifier::Abstract)
         .parameter(Parameter::new(Name::from("args"), Type::array(Type::string())))
         .returns(Type::array(Type::string()))
         .receiver(Type::int())
@@ -44,6 +43,7 @@ fn generic_file() {
         .function(function)
         .class(class);
 
+    assert_eq!(file.validate(), Ok(()));
     assert_rendered(
         "tests/samples/generic_file.kt",
         file.render_string().as_str(),
@@ -88,7 +88,7 @@ fn class_with_companion_object() {
 
     assert_rendered(
         "tests/samples/class_with_companion_object.kt",
-        &&class.render_string().as_str(),
+        class.render_string().as_str(),
     )
 }
 
@@ -96,7 +96,7 @@ fn class_with_companion_object() {
 fn kdoc_comment() {
     assert_rendered(
         "tests/samples/kdoc.kt",
-        &KDoc::new()
+        KDoc::new()
             .append("Hello\nWorld")
             .merge(KDoc::new().append("Wow such documentation"))
             .render_string().as_str()